@@ -0,0 +1,56 @@
+//! bincode 2 support.
+//!
+//! `Bytes` encodes like `[u8]`. [`Decode`] copies into owned storage, while [`BorrowDecode`]
+//! borrows from the input.
+
+use crate::Bytes;
+use alloc::vec::Vec;
+use bincode::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+impl<'b> Encode for Bytes<'b> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_slice().encode(encoder)
+    }
+}
+
+impl<'b, Context> Decode<Context> for Bytes<'b> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Vec::<u8>::decode(decoder).map(Self::from)
+    }
+}
+
+impl<'de: 'b, 'b, Context> BorrowDecode<'de, Context> for Bytes<'b> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        <&'b [u8]>::borrow_decode(decoder).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+
+    #[test]
+    fn round_trip() {
+        let config = bincode::config::standard();
+        let buf = bincode::encode_to_vec(Bytes::from("payload"), config).unwrap();
+
+        let (owned, _): (Bytes<'static>, _) = bincode::decode_from_slice(&buf, config).unwrap();
+        assert_eq!(owned, "payload");
+        assert_eq!(owned.storage_kind(), StorageKind::Unique);
+
+        let (borrowed, read): (Bytes<'_>, _) =
+            bincode::borrow_decode_from_slice(&buf, config).unwrap();
+        assert_eq!(read, buf.len());
+        assert_eq!(borrowed, "payload");
+        assert_eq!(borrowed.storage_kind(), StorageKind::Borrowed);
+        assert!(buf.as_ptr_range().contains(&borrowed.as_ptr()));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{BufMut, Bytes, BytesBuilder, LocalBytes};
+use core::ops::RangeBounds;
+
+/// A cheaply sliceable byte container, so parsers can be generic over which one they are
+/// handed. Slicing and splitting never copy.
+///
+/// ```
+/// # use lifetimed_bytes::{Bytes, LifetimedBuf};
+/// fn take_line<B: LifetimedBuf>(buf: &mut B) -> Option<B> {
+///     let pos = buf.as_slice().iter().position(|&b| b == b'\n')?;
+///     let line = buf.split_to(pos + 1);
+///     Some(line.slice(..pos))
+/// }
+///
+/// let mut input = Bytes::from("a\nb\n");
+/// assert_eq!(take_line(&mut input).unwrap(), "a");
+/// let mut input: &[u8] = b"a\nb\n";
+/// assert_eq!(take_line(&mut input).unwrap(), b"a");
+/// assert_eq!(input, b"b\n");
+/// ```
+pub trait LifetimedBuf: Sized {
+    fn as_slice(&self) -> &[u8];
+
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self;
+
+    /// Splits off and returns `[0, at)`, leaving `[at, len)` in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    fn split_to(&mut self, at: usize) -> Self;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'b> LifetimedBuf for Bytes<'b> {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        Bytes::slice(self, range)
+    }
+
+    fn split_to(&mut self, at: usize) -> Self {
+        Bytes::split_to(self, at)
+    }
+}
+
+impl<'b> LifetimedBuf for LocalBytes<'b> {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        LocalBytes::slice(self, range)
+    }
+
+    fn split_to(&mut self, at: usize) -> Self {
+        LocalBytes::split_to(self, at)
+    }
+}
+
+impl LifetimedBuf for bytes::Bytes {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        bytes::Bytes::slice(self, range)
+    }
+
+    fn split_to(&mut self, at: usize) -> Self {
+        bytes::Bytes::split_to(self, at)
+    }
+}
+
+impl LifetimedBuf for &[u8] {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        &self[(range.start_bound().cloned(), range.end_bound().cloned())]
+    }
+
+    fn split_to(&mut self, at: usize) -> Self {
+        let (head, tail) = self.split_at(at);
+        *self = tail;
+        head
+    }
+}
+
+/// Framing helpers for anything implementing [`BufMut`], and for [`BytesBuilder`], which
+/// keeps large [`Bytes`] as shared segments instead of copying them.
+///
+/// ```
+/// # use lifetimed_bytes::{BufMutExt, Bytes, BytesBuilder, BytesMut};
+/// let mut buf = BytesMut::new();
+/// buf.put_length_prefixed(&b"hello"[..]);
+/// assert_eq!(buf, b"\x05hello"[..]);
+///
+/// let payload = vec![0; 1000];
+/// let mut builder = BytesBuilder::new();
+/// builder.put_length_prefixed(&payload);
+/// assert_eq!(builder.freeze().segment_count(), 2);
+/// ```
+pub trait BufMutExt<'b> {
+    /// Writes `n` as an unsigned LEB128 varint, as used by protobuf.
+    fn put_varint(&mut self, n: u64);
+
+    /// Writes the length of `data` as a varint followed by `data` itself.
+    fn put_length_prefixed(&mut self, data: impl Into<Bytes<'b>>) {
+        let data = data.into();
+        self.put_varint(data.len() as u64);
+        self.put_lifetimed(data);
+    }
+
+    /// Appends `data`, sharing its storage rather than copying where the target allows.
+    fn put_lifetimed(&mut self, data: Bytes<'b>);
+}
+
+fn encode_varint(mut n: u64, buf: &mut [u8; 10]) -> &[u8] {
+    let mut len = 0;
+    while n >= 0x80 {
+        buf[len] = n as u8 | 0x80;
+        n >>= 7;
+        len += 1;
+    }
+    buf[len] = n as u8;
+    &buf[..=len]
+}
+
+impl<'b, B: BufMut + ?Sized> BufMutExt<'b> for B {
+    fn put_varint(&mut self, n: u64) {
+        self.put_slice(encode_varint(n, &mut [0; 10]))
+    }
+
+    fn put_lifetimed(&mut self, data: Bytes<'b>) {
+        crate::instrument::record_copy(data.len());
+        self.put_slice(&data)
+    }
+}
+
+impl<'b> BufMutExt<'b> for BytesBuilder<'b> {
+    fn put_varint(&mut self, n: u64) {
+        self.extend_from_slice(encode_varint(n, &mut [0; 10]))
+    }
+
+    fn put_lifetimed(&mut self, data: Bytes<'b>) {
+        self.push_bytes(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn varint() {
+        let mut buf = Vec::new();
+        for n in [0, 1, 127, 128, 300, u64::MAX] {
+            buf.put_varint(n);
+        }
+        assert_eq!(
+            buf,
+            [
+                &[0x00, 0x01, 0x7f, 0x80, 0x01, 0xac, 0x02][..],
+                &[0xff; 9],
+                &[0x01]
+            ]
+            .concat()
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Bytes, SegmentedBytes};
+use bytes::BytesMut;
+
+const DEFAULT_SEGMENT_THRESHOLD: usize = 256;
+
+/// Assembles a buffer from a mix of copied data and shared [`Bytes`] segments.
+///
+/// Small pieces are copied into a pending buffer, while [`Bytes`] of at least the segment
+/// threshold are kept as separate segments to avoid copying them.
+#[derive(Debug)]
+pub struct BytesBuilder<'b> {
+    segments: SegmentedBytes<'b>,
+    pending: BytesMut,
+    segment_threshold: usize,
+}
+
+impl<'b> Default for BytesBuilder<'b> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'b> BytesBuilder<'b> {
+    pub fn new() -> Self {
+        Self::with_segment_threshold(DEFAULT_SEGMENT_THRESHOLD)
+    }
+
+    /// Creates a builder that keeps pushed [`Bytes`] of `threshold` bytes or more as segments.
+    pub fn with_segment_threshold(threshold: usize) -> Self {
+        Self {
+            segments: SegmentedBytes::new(),
+            pending: BytesMut::new(),
+            segment_threshold: threshold,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len() + self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn extend_from_slice(&mut self, data: &[u8]) {
+        self.pending.extend_from_slice(data)
+    }
+
+    pub fn push_bytes(&mut self, b: Bytes<'b>) {
+        if b.len() >= self.segment_threshold {
+            crate::instrument::record_share(b.len());
+            self.flush();
+            self.segments.push(b);
+        } else {
+            crate::instrument::record_copy(b.len());
+            self.pending.extend_from_slice(&b);
+        }
+    }
+
+    pub fn freeze(mut self) -> SegmentedBytes<'b> {
+        self.flush();
+        self.segments
+    }
+
+    /// Finishes into a single buffer, copying only if more than one segment was produced.
+    pub fn freeze_contiguous(self) -> Bytes<'b> {
+        self.freeze().into_contiguous()
+    }
+
+    fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            self.segments.push(self.pending.split().into());
+        }
+    }
+}
+
+impl<'b> Extend<u8> for BytesBuilder<'b> {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        self.pending.extend(iter)
+    }
+}
+
+impl<'a, 'b> Extend<&'a u8> for BytesBuilder<'b> {
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.pending.extend(iter)
+    }
+}
+
+impl<'b> Extend<Bytes<'b>> for BytesBuilder<'b> {
+    fn extend<T: IntoIterator<Item = Bytes<'b>>>(&mut self, iter: T) {
+        for b in iter {
+            self.push_bytes(b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Buf;
+
+    #[test]
+    fn builder() {
+        let body = b"x".repeat(300);
+        let mut builder = BytesBuilder::new();
+        builder.extend_from_slice(b"HTTP/1.1 200 OK\r\n");
+        builder.extend(*b"\r\n");
+        builder.push_bytes(Bytes::from(body.as_slice()));
+        builder.push_bytes(Bytes::from("trailer"));
+
+        let mut segmented = builder.freeze();
+        assert_eq!(segmented.segment_count(), 3);
+        assert_eq!(segmented.len(), 19 + 300 + 7);
+        assert_eq!(segmented.segments().nth(1).unwrap().as_ptr(), body.as_ptr());
+
+        segmented.advance(20);
+        assert_eq!(segmented.chunk().len(), 299);
+        assert_eq!(segmented.clone().into_contiguous().len(), 306);
+        assert_eq!(segmented.copy_to_bytes(305).len(), 305);
+        assert_eq!(segmented.chunk(), b"r");
+    }
+}
@@ -0,0 +1,160 @@
+//! zstd and deflate compression into owned `Bytes`.
+//!
+//! Decompression takes a `max_size` and fails with [`io::ErrorKind::InvalidData`] once the
+//! output would exceed it, so untrusted input can't expand without bound. The
+//! [`SegmentedBytes`] variants stream the segments through without joining them first.
+
+use crate::{Buf, Bytes, SegmentedBytes};
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+
+fn compress_with<W: Write>(
+    mut encoder: W,
+    segments: impl Iterator<Item = impl AsRef<[u8]>>,
+    finish: impl FnOnce(W) -> io::Result<Vec<u8>>,
+) -> io::Result<Bytes<'static>> {
+    for segment in segments {
+        encoder.write_all(segment.as_ref())?;
+    }
+    finish(encoder).map(Bytes::from)
+}
+
+fn read_limited(reader: impl Read, max_size: usize) -> io::Result<Bytes<'static>> {
+    let mut out = Vec::new();
+    reader
+        .take((max_size as u64).saturating_add(1))
+        .read_to_end(&mut out)?;
+    if out.len() > max_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "decompressed data exceeds the size limit",
+        ));
+    }
+    Ok(out.into())
+}
+
+#[cfg(feature = "zstd")]
+impl<'b> Bytes<'b> {
+    pub fn compress_zstd(&self, level: i32) -> io::Result<Bytes<'static>> {
+        zstd::bulk::compress(self, level).map(Bytes::from)
+    }
+
+    pub fn decompress_zstd(&self, max_size: usize) -> io::Result<Bytes<'static>> {
+        read_limited(zstd::Decoder::with_buffer(&self[..])?, max_size)
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<'b> SegmentedBytes<'b> {
+    pub fn compress_zstd(&self, level: i32) -> io::Result<Bytes<'static>> {
+        compress_with(
+            zstd::Encoder::new(Vec::new(), level)?,
+            self.segments(),
+            zstd::Encoder::finish,
+        )
+    }
+
+    pub fn decompress_zstd(&self, max_size: usize) -> io::Result<Bytes<'static>> {
+        read_limited(
+            zstd::Decoder::with_buffer(io::BufReader::new(self.clone().reader()))?,
+            max_size,
+        )
+    }
+}
+
+#[cfg(feature = "deflate")]
+impl<'b> Bytes<'b> {
+    /// Raw deflate (RFC 1951) at `level` 0-9.
+    pub fn compress_deflate(&self, level: u32) -> io::Result<Bytes<'static>> {
+        compress_with(
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::new(level)),
+            core::iter::once(self),
+            flate2::write::DeflateEncoder::finish,
+        )
+    }
+
+    pub fn decompress_deflate(&self, max_size: usize) -> io::Result<Bytes<'static>> {
+        read_limited(flate2::bufread::DeflateDecoder::new(&self[..]), max_size)
+    }
+}
+
+#[cfg(feature = "deflate")]
+impl<'b> SegmentedBytes<'b> {
+    /// Raw deflate (RFC 1951) at `level` 0-9.
+    pub fn compress_deflate(&self, level: u32) -> io::Result<Bytes<'static>> {
+        compress_with(
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::new(level)),
+            self.segments(),
+            flate2::write::DeflateEncoder::finish,
+        )
+    }
+
+    pub fn decompress_deflate(&self, max_size: usize) -> io::Result<Bytes<'static>> {
+        read_limited(
+            flate2::read::DeflateDecoder::new(self.clone().reader()),
+            max_size,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> SegmentedBytes<'static> {
+        ["header;", "body body body body;", "trailer"]
+            .into_iter()
+            .map(Bytes::from)
+            .collect()
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd() {
+        let segments = input();
+        let joined = segments.clone().into_contiguous();
+
+        let compressed = segments.compress_zstd(3).unwrap();
+        assert_eq!(compressed.decompress_zstd(joined.len()).unwrap(), joined);
+        assert_eq!(
+            joined
+                .compress_zstd(3)
+                .unwrap()
+                .decompress_zstd(64)
+                .unwrap(),
+            joined
+        );
+        assert!(compressed.decompress_zstd(joined.len() - 1).is_err());
+
+        let split = SegmentedBytes::from_iter([compressed.slice(..5), compressed.slice(5..)]);
+        assert_eq!(split.decompress_zstd(64).unwrap(), joined);
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn deflate() {
+        let segments = input();
+        let joined = segments.clone().into_contiguous();
+
+        let compressed = segments.compress_deflate(6).unwrap();
+        assert_eq!(compressed.decompress_deflate(joined.len()).unwrap(), joined);
+        assert_eq!(
+            joined
+                .compress_deflate(6)
+                .unwrap()
+                .decompress_deflate(64)
+                .unwrap(),
+            joined
+        );
+        assert!(compressed.decompress_deflate(10).is_err());
+
+        let split = SegmentedBytes::from_iter([compressed.slice(..3), compressed.slice(3..)]);
+        assert_eq!(split.decompress_deflate(64).unwrap(), joined);
+    }
+
+    #[test]
+    fn unlimited() {
+        let out = read_limited(&b"payload"[..], usize::MAX).unwrap();
+        assert_eq!(out, "payload");
+    }
+}
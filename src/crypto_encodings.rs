@@ -0,0 +1,71 @@
+//! Base58(Check) and bech32/bech32m, the text encodings used for blockchain addresses and keys.
+
+use crate::Bytes;
+use alloc::string::String;
+use bech32::{Bech32, Bech32m, Hrp};
+
+impl<'b> Bytes<'b> {
+    pub fn from_base58(s: &str) -> Result<Self, bs58::decode::Error> {
+        bs58::decode(s).into_vec().map(Self::from)
+    }
+
+    /// Decodes Base58Check, verifying and stripping the 4-byte checksum.
+    pub fn from_base58check(s: &str) -> Result<Self, bs58::decode::Error> {
+        bs58::decode(s).with_check(None).into_vec().map(Self::from)
+    }
+
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self).into_string()
+    }
+
+    /// Encodes as Base58Check, appending a 4-byte checksum.
+    pub fn to_base58check(&self) -> String {
+        bs58::encode(self).with_check().into_string()
+    }
+
+    /// Decodes a bech32 or bech32m string into its human-readable part and data.
+    pub fn from_bech32(s: &str) -> Result<(Hrp, Self), bech32::DecodeError> {
+        let (hrp, data) = bech32::decode(s)?;
+        Ok((hrp, data.into()))
+    }
+
+    pub fn to_bech32(&self, hrp: Hrp) -> Result<String, bech32::EncodeError> {
+        bech32::encode::<Bech32>(hrp, self)
+    }
+
+    pub fn to_bech32m(&self, hrp: Hrp) -> Result<String, bech32::EncodeError> {
+        bech32::encode::<Bech32m>(hrp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58() {
+        let b = Bytes::from("hello world");
+        assert_eq!(b.to_base58(), "StV1DL6CwTryKyV");
+        assert_eq!(Bytes::from_base58("StV1DL6CwTryKyV").unwrap(), b);
+        assert!(Bytes::from_base58("0OIl").is_err());
+
+        let checked = b.to_base58check();
+        assert_eq!(Bytes::from_base58check(&checked).unwrap(), b);
+        assert!(Bytes::from_base58check(&b.to_base58()).is_err());
+    }
+
+    #[test]
+    fn bech32() {
+        let hrp = Hrp::parse("bc").unwrap();
+        let b = Bytes::from(&[0x75, 0x1e, 0x76, 0xe8][..]);
+
+        let encoded = b.to_bech32(hrp).unwrap();
+        let (decoded_hrp, decoded) = Bytes::from_bech32(&encoded).unwrap();
+        assert_eq!((decoded_hrp, decoded), (hrp, b.clone()));
+
+        let encoded_m = b.to_bech32m(hrp).unwrap();
+        assert_ne!(encoded, encoded_m);
+        assert_eq!(Bytes::from_bech32(&encoded_m).unwrap().1, b);
+        assert!(Bytes::from_bech32("bc1invalid").is_err());
+    }
+}
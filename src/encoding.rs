@@ -0,0 +1,46 @@
+use crate::Bytes;
+use alloc::borrow::Cow;
+use encoding_rs::Encoding;
+
+impl<'b> Bytes<'b> {
+    /// Decodes legacy-encoded text (e.g. `encoding_rs::SHIFT_JIS`), replacing malformed
+    /// sequences with U+FFFD. The flag tells whether any had to be replaced.
+    ///
+    /// A leading byte order mark is stripped if it matches `encoding`. The text is borrowed
+    /// when the data is already valid UTF-8 in a UTF-8 compatible encoding.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// let body = Bytes::from(&b"caf\xe9"[..]);
+    /// let (text, had_errors) = body.decode_with(encoding_rs::WINDOWS_1252);
+    /// assert_eq!((&*text, had_errors), ("café", false));
+    /// ```
+    pub fn decode_with(&self, encoding: &'static Encoding) -> (Cow<'_, str>, bool) {
+        encoding.decode_with_bom_removal(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::{SHIFT_JIS, UTF_8, WINDOWS_1252};
+
+    #[test]
+    fn decode() {
+        let b = Bytes::from("\u{feff}plain");
+        let (text, had_errors) = b.decode_with(UTF_8);
+        assert!(matches!(text, Cow::Borrowed("plain")));
+        assert!(!had_errors);
+
+        let ascii = Bytes::from("ascii");
+        assert!(matches!(
+            ascii.decode_with(WINDOWS_1252).0,
+            Cow::Borrowed(_)
+        ));
+
+        let b = Bytes::from(&b"\x93\xfa\x96\x7b\xff"[..]);
+        let (text, had_errors) = b.decode_with(SHIFT_JIS);
+        assert_eq!(text, "日本\u{fffd}");
+        assert!(had_errors);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Bytes;
+use core::fmt::{self, Debug, Display, Write};
+
+/// `Display` adapter rendering bytes with ASCII escaping, see [`Bytes::escape_ascii`].
+#[derive(Clone, Copy)]
+pub struct EscapeAscii<'a> {
+    data: &'a [u8],
+    max_len: Option<usize>,
+}
+
+impl<'a> EscapeAscii<'a> {
+    /// Renders at most `max_len` input bytes, followed by `...` if anything was cut off.
+    pub fn max_len(self, max_len: usize) -> Self {
+        Self {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
+}
+
+impl<'a> Display for EscapeAscii<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max_len {
+            Some(max_len) if self.data.len() > max_len => {
+                Display::fmt(&self.data[..max_len].escape_ascii(), f)?;
+                f.write_str("...")
+            }
+            _ => Display::fmt(&self.data.escape_ascii(), f),
+        }
+    }
+}
+
+impl<'a> Debug for EscapeAscii<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        Display::fmt(self, f)?;
+        f.write_char('"')
+    }
+}
+
+impl<'b> Bytes<'b> {
+    /// Returns an adapter displaying the data with non-printable bytes escaped, e.g.
+    /// `GET /\r\n\x00`.
+    pub fn escape_ascii(&self) -> EscapeAscii<'_> {
+        EscapeAscii {
+            data: self,
+            max_len: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bytes;
+    use alloc::format;
+
+    #[test]
+    fn escape_ascii() {
+        let b = Bytes::from(b"GET /\r\n\x00\xff");
+        assert_eq!(format!("{}", b.escape_ascii()), r"GET /\r\n\x00\xff");
+        assert_eq!(format!("{}", b.escape_ascii().max_len(3)), "GET...");
+        assert_eq!(
+            format!("{:?}", b.escape_ascii().max_len(20)),
+            r#""GET /\r\n\x00\xff""#
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! A panic-free view of the buffer API.
+//!
+//! [`fallible::Bytes`](Bytes) wraps [`crate::Bytes`] and only offers its bounds-checked
+//! operations, each returning a [`Result`] or [`Option`], so code written against it can't
+//! abort on a bad offset. The same checks are available on [`crate::Bytes`] itself as the
+//! `try_*` methods, alongside the panicking ones.
+//!
+//! ```
+//! use lifetimed_bytes::fallible::Bytes;
+//!
+//! let mut frame = Bytes::from(lifetimed_bytes::Bytes::from("\x04body"));
+//! let len = frame.peek_u8().unwrap() as usize;
+//! frame.advance(1).unwrap();
+//! assert_eq!(frame.split_to(len).unwrap().into_inner(), "body");
+//! assert!(frame.split_to(1).is_err());
+//! ```
+
+use crate::Buf;
+use core::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+type Inner<'b> = crate::Bytes<'b>;
+
+/// A range or position that doesn't fit the buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// Requested start, saturated if it overflowed.
+    pub start: usize,
+    /// Requested end, saturated if it overflowed.
+    pub end: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "range {}..{} out of bounds for length {}",
+            self.start, self.end, self.len
+        )
+    }
+}
+
+impl core::error::Error for OutOfBounds {}
+
+fn check_range(range: impl RangeBounds<usize>, len: usize) -> Result<(usize, usize), OutOfBounds> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => Some(n),
+        Bound::Excluded(&n) => n.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1),
+        Bound::Excluded(&n) => Some(n),
+        Bound::Unbounded => Some(len),
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= len => Ok((start, end)),
+        (start, end) => Err(OutOfBounds {
+            start: start.unwrap_or(usize::MAX),
+            end: end.unwrap_or(usize::MAX),
+            len,
+        }),
+    }
+}
+
+impl<'b> Inner<'b> {
+    /// Like [`Bytes::slice`](Inner::slice), but fails instead of panicking.
+    pub fn try_slice(&self, range: impl RangeBounds<usize>) -> Result<Self, OutOfBounds> {
+        let (start, end) = check_range(range, self.len())?;
+        Ok(self.slice(start..end))
+    }
+
+    /// Like [`Bytes::split_off`](Inner::split_off), but fails instead of panicking.
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, OutOfBounds> {
+        check_range(at.., self.len())?;
+        Ok(self.split_off(at))
+    }
+
+    /// Like [`Bytes::split_to`](Inner::split_to), but fails instead of panicking.
+    pub fn try_split_to(&mut self, at: usize) -> Result<Self, OutOfBounds> {
+        check_range(..at, self.len())?;
+        Ok(self.split_to(at))
+    }
+
+    /// Like [`Buf::advance`], but fails instead of panicking.
+    pub fn try_advance(&mut self, cnt: usize) -> Result<(), OutOfBounds> {
+        check_range(cnt.., self.len())?;
+        self.advance(cnt);
+        Ok(())
+    }
+}
+
+/// A [`crate::Bytes`] restricted to operations that can't panic.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bytes<'b>(Inner<'b>);
+
+impl<'b> Bytes<'b> {
+    pub fn into_inner(self) -> Inner<'b> {
+        self.0
+    }
+
+    pub fn as_inner(&self) -> &Inner<'b> {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Result<Self, OutOfBounds> {
+        self.0.try_slice(range).map(Self)
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Result<Self, OutOfBounds> {
+        self.0.try_split_off(at).map(Self)
+    }
+
+    pub fn split_to(&mut self, at: usize) -> Result<Self, OutOfBounds> {
+        self.0.try_split_to(at).map(Self)
+    }
+
+    pub fn advance(&mut self, cnt: usize) -> Result<(), OutOfBounds> {
+        self.0.try_advance(cnt)
+    }
+
+    /// Shortens the buffer to `len` bytes, failing if it is shorter than that.
+    pub fn truncate(&mut self, len: usize) -> Result<(), OutOfBounds> {
+        check_range(..len, self.len())?;
+        self.0.truncate(len);
+        Ok(())
+    }
+
+    pub fn peek_u8(&self) -> Option<u8> {
+        self.0.peek_u8()
+    }
+
+    pub fn peek_array<const N: usize>(&self) -> Option<[u8; N]> {
+        self.0.peek_array()
+    }
+
+    pub fn peek_slice(&self, len: usize) -> Result<Self, OutOfBounds> {
+        self.slice(..len)
+    }
+}
+
+impl<'b> From<Inner<'b>> for Bytes<'b> {
+    fn from(inner: Inner<'b>) -> Self {
+        Self(inner)
+    }
+}
+
+impl<'b> From<Bytes<'b>> for Inner<'b> {
+    fn from(bytes: Bytes<'b>) -> Self {
+        bytes.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes<'_> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Bytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_bounds() {
+        let mut b = Inner::from("header:body");
+        assert_eq!(b.try_slice(7..).unwrap(), "body");
+        assert_eq!(
+            b.try_slice(5..=20),
+            Err(OutOfBounds {
+                start: 5,
+                end: 21,
+                len: 11
+            })
+        );
+        assert!(b
+            .try_slice((Bound::Included(4), Bound::Excluded(2)))
+            .is_err());
+        assert!(b
+            .try_slice((Bound::Excluded(usize::MAX), Bound::Unbounded))
+            .is_err());
+
+        assert!(b.try_split_to(12).is_err());
+        assert_eq!(b.try_split_to(7).unwrap(), "header:");
+        assert!(b.try_advance(5).is_err());
+        b.try_advance(1).unwrap();
+        assert_eq!(b.try_split_off(1).unwrap(), "dy");
+        assert_eq!(b, "o");
+    }
+
+    #[test]
+    fn wrapper() {
+        let mut b = Bytes::from(Inner::from("header:body"));
+        assert_eq!(b.peek_slice(6).unwrap().into_inner(), "header");
+        assert!(b.peek_slice(12).is_err());
+        assert!(b.truncate(12).is_err());
+        b.truncate(9).unwrap();
+        assert!(b.split_off(10).is_err());
+        assert_eq!(b.split_off(7).unwrap().as_ref(), b"bo");
+        b.advance(6).unwrap();
+        assert_eq!(b.peek_u8(), Some(b':'));
+        assert!(b.advance(2).is_err());
+        assert_eq!(b.len(), 1);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Bytes;
+use alloc::boxed::Box;
+use core::{ffi::c_void, marker::PhantomData, slice};
+
+/// Immutable `#[repr(C)]` view over a byte buffer, valid for `'a`.
+///
+/// Meant to be handed to C callbacks that only look at the data for the duration of the call.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RawBytes<'a> {
+    ptr: *const u8,
+    len: usize,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+impl<'a> RawBytes<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            ptr: data.as_ptr(),
+            len: data.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn ptr(&self) -> *const u8 {
+        self.ptr
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_slice(&self) -> &'a [u8] {
+        // SAFETY: constructed from a slice borrowed for `'a`
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<'a> From<&'a [u8]> for RawBytes<'a> {
+    fn from(data: &'a [u8]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<'b> Bytes<'b> {
+    pub fn as_raw(&self) -> RawBytes<'_> {
+        RawBytes::new(self)
+    }
+
+    /// Decomposes the buffer into a data pointer, a length and an opaque owner handle.
+    ///
+    /// The data stays valid until the handle is passed back to [`Bytes::from_raw_parts`].
+    /// Not doing so leaks the buffer.
+    pub fn into_raw_parts(self) -> (*const u8, usize, *mut c_void) {
+        let ptr = self.as_ptr();
+        let len = self.len();
+        let owner = Box::into_raw(Box::new(self));
+        (ptr, len, owner.cast())
+    }
+
+    /// Reassembles a buffer from parts returned by [`Bytes::into_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// `owner` must come from a call to [`Bytes::into_raw_parts`] on a `Bytes<'b>` and must not
+    /// be reused afterwards. `ptr` and `len` must describe a subrange of the data returned by that
+    /// call.
+    pub unsafe fn from_raw_parts(ptr: *const u8, len: usize, owner: *mut c_void) -> Self {
+        // SAFETY: guaranteed by the caller
+        let b = unsafe { *Box::from_raw(owner.cast::<Bytes<'b>>()) };
+        let start = (ptr as usize).wrapping_sub(b.as_ptr() as usize);
+        debug_assert!(start <= b.len() && len <= b.len() - start);
+        b.slice(start..start + len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bytes;
+
+    #[test]
+    fn raw_parts_roundtrip() {
+        let v = b"hello world".to_vec();
+        let (ptr, len, owner) = Bytes::from(v.as_slice()).into_raw_parts();
+        assert_eq!(len, 11);
+
+        // SAFETY: subrange of the parts returned above
+        let b = unsafe { Bytes::from_raw_parts(ptr.wrapping_add(6), 5, owner) };
+        assert_eq!(b, "world");
+        assert_eq!(b.as_raw().as_slice(), b"world");
+    }
+}
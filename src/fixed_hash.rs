@@ -0,0 +1,99 @@
+//! Conversions to and from the fixed-size hash types of `alloy-primitives` and
+//! `primitive-types`.
+//!
+//! Converting into `Bytes` copies into owned storage; converting out checks the length.
+
+use crate::Bytes;
+use core::array::TryFromSliceError;
+
+#[cfg(feature = "alloy-primitives")]
+mod alloy {
+    use super::*;
+    use alloy_primitives::{Address, FixedBytes};
+
+    impl<'b, const N: usize> From<FixedBytes<N>> for Bytes<'b> {
+        fn from(hash: FixedBytes<N>) -> Self {
+            hash.as_slice().to_vec().into()
+        }
+    }
+
+    impl<'b> From<Address> for Bytes<'b> {
+        fn from(address: Address) -> Self {
+            address.as_slice().to_vec().into()
+        }
+    }
+
+    impl<'a, 'b, const N: usize> TryFrom<&'a Bytes<'b>> for FixedBytes<N> {
+        type Error = TryFromSliceError;
+
+        fn try_from(bytes: &'a Bytes<'b>) -> Result<Self, Self::Error> {
+            bytes.as_fixed().map(|a| Self(*a))
+        }
+    }
+
+    impl<'a, 'b> TryFrom<&'a Bytes<'b>> for Address {
+        type Error = TryFromSliceError;
+
+        fn try_from(bytes: &'a Bytes<'b>) -> Result<Self, Self::Error> {
+            bytes.as_fixed().map(|a| Self::new(*a))
+        }
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+mod primitive {
+    use super::*;
+    use primitive_types::{H160, H256};
+
+    macro_rules! hash_impls {
+        ($($ty:ident),*) => {$(
+            impl<'b> From<$ty> for Bytes<'b> {
+                fn from(hash: $ty) -> Self {
+                    hash.as_bytes().to_vec().into()
+                }
+            }
+
+            impl<'a, 'b> TryFrom<&'a Bytes<'b>> for $ty {
+                type Error = TryFromSliceError;
+
+                fn try_from(bytes: &'a Bytes<'b>) -> Result<Self, Self::Error> {
+                    bytes.as_fixed().map(|a| Self(*a))
+                }
+            }
+        )*};
+    }
+
+    hash_impls!(H160, H256);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloy-primitives")]
+    #[test]
+    fn alloy() {
+        use alloy_primitives::{Address, B256};
+
+        let hash = B256::repeat_byte(0xab);
+        let b = Bytes::from(hash);
+        assert_eq!(b.len(), 32);
+        assert_eq!(B256::try_from(&b).unwrap(), hash);
+        assert!(Address::try_from(&b).is_err());
+        assert_eq!(
+            Address::try_from(&b.slice(..20)).unwrap(),
+            Address::repeat_byte(0xab)
+        );
+    }
+
+    #[cfg(feature = "primitive-types")]
+    #[test]
+    fn primitive_types() {
+        use primitive_types::{H160, H256};
+
+        let hash = H256::repeat_byte(0xcd);
+        let b = Bytes::from(hash);
+        assert_eq!(H256::try_from(&b).unwrap(), hash);
+        assert!(H160::try_from(&b).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(feature = "serde")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "serde")]
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+#[cfg(feature = "serde")]
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut s = String::with_capacity(data.len() * 2);
+    for &b in data {
+        s.push(DIGITS[(b >> 4) as usize] as char);
+        s.push(DIGITS[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+pub(crate) const fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn decode(s: &[u8]) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+
+    s.chunks_exact(2)
+        .map(|pair| Some((digit(pair[0])? << 4) | digit(pair[1])?))
+        .collect()
+}
+
+pub const fn decoded_len(s: &str) -> usize {
+    assert!(
+        s.len().is_multiple_of(2),
+        "hex literal must have an even number of digits"
+    );
+    s.len() / 2
+}
+
+pub const fn decode_array<const N: usize>(s: &str) -> [u8; N] {
+    let s = s.as_bytes();
+    assert!(s.len() == N * 2, "hex literal length mismatch");
+
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        let (Some(hi), Some(lo)) = (digit(s[2 * i]), digit(s[2 * i + 1])) else {
+            panic!("invalid digit in hex literal");
+        };
+        out[i] = (hi << 4) | lo;
+        i += 1;
+    }
+    out
+}
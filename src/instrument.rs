@@ -0,0 +1,95 @@
+//! Process-wide counters of how often this crate copied data versus shared it, for auditing
+//! zero-copy pipelines.
+//!
+//! Recorded at the points where an API may either share or copy: [`Buf::copy_to_bytes`],
+//! [`Bytes::into_shared`], [`SegmentedBytes::into_contiguous`], [`BytesBuilder::push_bytes`],
+//! [`BufMutExt::put_lifetimed`], [`BytesInterner::intern`] and [`BytesInterner::intern_bytes`]
+//! misses, [`LocalBytes`] to [`Bytes`] conversion, the ASCII case mapping methods, serde
+//! deserialization when the format can't lend borrowed bytes, and minicbor decoding of
+//! indefinite-length byte strings.
+//!
+//! [`Buf::copy_to_bytes`]: crate::Buf::copy_to_bytes
+//! [`SegmentedBytes::into_contiguous`]: crate::SegmentedBytes::into_contiguous
+//! [`BytesBuilder::push_bytes`]: crate::BytesBuilder::push_bytes
+//! [`BufMutExt::put_lifetimed`]: crate::BufMutExt::put_lifetimed
+//! [`BytesInterner::intern`]: crate::BytesInterner::intern
+//! [`BytesInterner::intern_bytes`]: crate::BytesInterner::intern_bytes
+//! [`LocalBytes`]: crate::LocalBytes
+//! [`Bytes`]: crate::Bytes
+//! [`Bytes::into_shared`]: crate::Bytes::into_shared
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static COPIES: AtomicUsize = AtomicUsize::new(0);
+static COPIED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static SHARES: AtomicUsize = AtomicUsize::new(0);
+static SHARED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CopyStats {
+    pub copies: usize,
+    pub copied_bytes: usize,
+    pub shares: usize,
+    pub shared_bytes: usize,
+}
+
+pub fn stats() -> CopyStats {
+    CopyStats {
+        copies: COPIES.load(Ordering::Relaxed),
+        copied_bytes: COPIED_BYTES.load(Ordering::Relaxed),
+        shares: SHARES.load(Ordering::Relaxed),
+        shared_bytes: SHARED_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+pub fn reset() {
+    for counter in [&COPIES, &COPIED_BYTES, &SHARES, &SHARED_BYTES] {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+pub(crate) fn record_copy(len: usize) {
+    COPIES.fetch_add(1, Ordering::Relaxed);
+    COPIED_BYTES.fetch_add(len, Ordering::Relaxed);
+}
+
+pub(crate) fn record_share(len: usize) {
+    SHARES.fetch_add(1, Ordering::Relaxed);
+    SHARED_BYTES.fetch_add(len, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Buf, Bytes};
+
+    #[test]
+    fn counts() {
+        let before = stats();
+
+        let v = b"hello world".to_vec();
+        let _ = Bytes::from(v.as_slice()).copy_to_bytes(5);
+        let _ = Bytes::from(v.clone()).copy_to_bytes(11);
+
+        // other tests may run concurrently, so only lower bounds hold
+        let after = stats();
+        assert!(after.copies > before.copies);
+        assert!(after.copied_bytes >= before.copied_bytes + 5);
+        assert!(after.shares > before.shares);
+        assert!(after.shared_bytes >= before.shared_bytes + 11);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_transient() {
+        let before = stats();
+
+        // a reader can't lend its input, so the JSON string reaches the visitor as transient bytes
+        let b: Bytes<'static> = serde_json::from_reader(&br#""transient""#[..]).unwrap();
+        assert_eq!(b, "transient");
+
+        let after = stats();
+        assert!(after.copies > before.copies);
+        assert!(after.copied_bytes >= before.copied_bytes + 9);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Bytes, Origin};
+use alloc::collections::BTreeSet;
+use core::marker::PhantomData;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InternerStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Number of distinct byte strings held.
+    pub entries: usize,
+    /// Total size of distinct byte strings held.
+    pub bytes: usize,
+}
+
+impl InternerStats {
+    /// Fraction of lookups that were served by an existing entry.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Deduplicates identical byte strings, handing out shared handles to a single copy.
+#[derive(Debug, Default)]
+pub struct BytesInterner {
+    set: BTreeSet<Bytes<'static>>,
+    stats: InternerStats,
+}
+
+impl BytesInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, data: &[u8]) -> Bytes<'static> {
+        self.lookup(data).unwrap_or_else(|| {
+            crate::instrument::record_copy(data.len());
+            self.insert(data.to_vec().into())
+        })
+    }
+
+    /// Like [`BytesInterner::intern`], but takes a handle.
+    ///
+    /// New entries share `b`'s storage only if it is static. Anything else is copied into an
+    /// allocation of its own, since `b` may be a small slice of a much larger buffer (e.g. a
+    /// key cut out of a network frame) that the entry would otherwise keep alive.
+    pub fn intern_bytes(&mut self, b: Bytes<'_>) -> Bytes<'static> {
+        if let Some(existing) = self.lookup(&b) {
+            return existing;
+        }
+
+        let b = if b.origin == Origin::Static {
+            crate::instrument::record_share(b.len());
+            Bytes {
+                inner: b.inner,
+                origin: Origin::Static,
+                _marker: PhantomData,
+            }
+        } else {
+            crate::instrument::record_copy(b.len());
+            b.to_vec().into()
+        };
+        self.insert(b)
+    }
+
+    /// Looks up an entry without recording a hit or miss.
+    pub fn get(&self, data: &[u8]) -> Option<Bytes<'static>> {
+        self.set.get(data).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    pub fn stats(&self) -> InternerStats {
+        self.stats
+    }
+
+    /// Drops all entries and resets the statistics. Handles already given out stay valid.
+    pub fn clear(&mut self) {
+        self.set.clear();
+        self.stats = InternerStats::default();
+    }
+
+    fn lookup(&mut self, data: &[u8]) -> Option<Bytes<'static>> {
+        let found = self.get(data);
+        if found.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        found
+    }
+
+    fn insert(&mut self, b: Bytes<'static>) -> Bytes<'static> {
+        self.stats.entries += 1;
+        self.stats.bytes += b.len();
+        self.set.insert(b.clone());
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interner() {
+        let mut interner = BytesInterner::new();
+        let a = interner.intern(b"column_a");
+        let b = interner.intern_bytes(Bytes::from("column_a"));
+        let c = interner.intern_bytes(Bytes::from(b"column_c".to_vec()));
+        assert_eq!(a.as_ptr(), b.as_ptr());
+        assert_eq!(c, "column_c");
+
+        let stats = interner.stats();
+        assert_eq!(
+            (stats.hits, stats.misses, stats.entries, stats.bytes),
+            (1, 2, 2, 16)
+        );
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn intern_slice_copies() {
+        let mut interner = BytesInterner::new();
+        let frame = Bytes::from(alloc::vec![7; 1 << 20]);
+        let key = interner.intern_bytes(frame.slice(..4));
+        assert_ne!(key.as_ptr(), frame.as_ptr());
+        assert!(frame.is_unique());
+
+        let name = interner.intern_bytes(Bytes::from_static(b"static"));
+        assert_eq!(name.as_ptr(), b"static".as_ptr());
+    }
+}
@@ -0,0 +1,175 @@
+//! `std::io` integration.
+
+use crate::{Bytes, SegmentedBytes};
+use alloc::vec::Vec;
+use core::cmp;
+use std::io::{self, BufRead, IoSlice, Read, Seek, SeekFrom, Write};
+
+impl<'b> Bytes<'b> {
+    pub fn as_io_slice(&self) -> IoSlice<'_> {
+        IoSlice::new(self)
+    }
+}
+
+impl<'b> SegmentedBytes<'b> {
+    /// One [`IoSlice`] per segment, for writing everything out with a single `writev`.
+    pub fn as_io_slices(&self) -> Vec<IoSlice<'_>> {
+        self.segments().map(Bytes::as_io_slice).collect()
+    }
+}
+
+/// A seekable reader over [`Bytes`], like `std::io::Cursor` but without copying the data into
+/// a `Vec` first. [`Cursor::read_bytes`] hands out views of the remaining data.
+#[derive(Clone, Debug, Default)]
+pub struct Cursor<'b> {
+    inner: Bytes<'b>,
+    pos: u64,
+}
+
+impl<'b> Cursor<'b> {
+    pub fn new(inner: Bytes<'b>) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// The position may be set past the end, after which reads return nothing.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    pub fn get_ref(&self) -> &Bytes<'b> {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> Bytes<'b> {
+        self.inner
+    }
+
+    /// Data from the current position to the end.
+    pub fn remaining(&self) -> Bytes<'b> {
+        self.inner.slice(self.offset()..)
+    }
+
+    /// Reads up to `n` bytes as a view into the underlying buffer.
+    pub fn read_bytes(&mut self, n: usize) -> Bytes<'b> {
+        let start = self.offset();
+        let end = start + cmp::min(n, self.inner.len() - start);
+        if start < end {
+            self.pos = end as u64;
+        }
+        self.inner.slice(start..end)
+    }
+
+    fn offset(&self) -> usize {
+        cmp::min(self.pos, self.inner.len() as u64) as usize
+    }
+}
+
+impl<'b> Read for Cursor<'b> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.fill_buf()?.read(buf)?;
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<'b> BufRead for Cursor<'b> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self.inner[self.offset()..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<'b> Seek for Cursor<'b> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.inner.len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// Writes all of `bufs` using vectored writes, retrying on interruption until everything is
+/// written. `bufs` is modified along the way.
+pub fn write_all_vectored<W: Write + ?Sized>(
+    writer: &mut W,
+    mut bufs: &mut [IoSlice<'_>],
+) -> io::Result<()> {
+    IoSlice::advance_slices(&mut bufs, 0);
+    while !bufs.is_empty() {
+        match writer.write_vectored(bufs) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectored() {
+        let segments = [Bytes::from("GET / HTTP/1.1\r\n"), Bytes::from("\r\n")]
+            .into_iter()
+            .collect::<SegmentedBytes>();
+
+        let mut out = Vec::new();
+        write_all_vectored(&mut out, &mut segments.as_io_slices()).unwrap();
+        assert_eq!(out, b"GET / HTTP/1.1\r\n\r\n");
+    }
+
+    #[test]
+    fn cursor() {
+        let mut c = Cursor::new(Bytes::from("PK\x03\x04 local header"));
+
+        let mut magic = [0; 4];
+        c.read_exact(&mut magic).unwrap();
+        assert_eq!(&magic, b"PK\x03\x04");
+
+        assert_eq!(c.seek(SeekFrom::End(-6)).unwrap(), 11);
+        assert_eq!(c.read_bytes(100), "header");
+        assert_eq!(c.read(&mut magic).unwrap(), 0);
+
+        assert_eq!(c.seek(SeekFrom::Current(-13)).unwrap(), 4);
+        assert_eq!(c.remaining(), " local header");
+        assert!(c.seek(SeekFrom::Current(-9)).is_err());
+
+        c.set_position(100);
+        assert!(c.remaining().is_empty());
+        assert!(c.read_bytes(1).is_empty());
+        assert_eq!(c.position(), 100);
+    }
+}
@@ -0,0 +1,615 @@
+//! Iterators over views of a [`Bytes`].
+//!
+//! Every item shares the storage of the buffer it came from, with the same lifetime.
+
+use crate::{Buf, BytePattern, Bytes};
+use core::mem;
+
+impl<'b> Bytes<'b> {
+    /// Views of `chunk_size` bytes each. A trailing partial chunk is left out of the iteration
+    /// and available through [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'b> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let mut rest = self.clone();
+        let remainder = rest.split_off(rest.len() - rest.len() % chunk_size);
+        ChunksExact {
+            rest,
+            remainder,
+            chunk_size,
+        }
+    }
+
+    /// Copies of consecutive `N`-byte blocks, e.g. for feeding a block cipher. A trailing
+    /// partial block is available through [`ArrayChunks::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'b, N> {
+        ArrayChunks {
+            inner: self.chunks_exact(N),
+        }
+    }
+
+    /// Views of the data between bytes matching `pat`, like [`slice::split`].
+    pub fn split<P: BytePattern>(&self, pat: P) -> Split<'b, P> {
+        Split {
+            rest: self.clone(),
+            pat,
+            finished: false,
+        }
+    }
+
+    /// Like [`Bytes::split`], but keeps each matched byte at the end of the view before it.
+    /// A trailing match doesn't produce an empty view.
+    pub fn split_inclusive<P: BytePattern>(&self, pat: P) -> SplitInclusive<'b, P> {
+        SplitInclusive {
+            rest: self.clone(),
+            pat,
+        }
+    }
+
+    /// Like [`Bytes::split`], but a trailing empty view is skipped, so the matched bytes act as
+    /// terminators rather than separators.
+    pub fn split_terminator<P: BytePattern>(&self, mut pat: P) -> SplitTerminator<'b, P> {
+        let mut rest = self.clone();
+        if rest.last().is_some_and(|&b| pat.matches(b)) {
+            rest.truncate(rest.len() - 1);
+        }
+
+        SplitTerminator {
+            inner: Split {
+                rest,
+                pat,
+                finished: self.is_empty(),
+            },
+        }
+    }
+
+    /// Non-empty views separated by ASCII whitespace.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// let cmd = Bytes::from("SET  key\tvalue\r\n");
+    /// assert!(cmd.split_ascii_whitespace().eq(["SET", "key", "value"]));
+    /// ```
+    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'b> {
+        SplitAsciiWhitespace {
+            inner: self.split(|b: u8| b.is_ascii_whitespace()),
+        }
+    }
+
+    /// Lines without their `\n` or `\r\n` ending, like [`str::lines`]. A final line ending is
+    /// optional and doesn't start another line.
+    pub fn lines(&self) -> Lines<'b> {
+        Lines {
+            inner: self.lines_terminated(),
+        }
+    }
+
+    /// Like [`Bytes::lines`], but also tells whether each line had an ending, so a partial last
+    /// line can be told apart (e.g. to wait for more input).
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// let log = Bytes::from("one\r\ntwo\nthr");
+    /// let lines: Vec<_> = log.lines_terminated().collect();
+    /// assert_eq!(lines, [("one".into(), true), ("two".into(), true), ("thr".into(), false)]);
+    /// ```
+    pub fn lines_terminated(&self) -> LinesTerminated<'b> {
+        LinesTerminated {
+            inner: self.split_inclusive(b'\n'),
+        }
+    }
+
+    /// Like [`Bytes::split`], starting from the end.
+    pub fn rsplit<P: BytePattern>(&self, pat: P) -> RSplit<'b, P> {
+        RSplit {
+            inner: self.split(pat),
+        }
+    }
+
+    /// Like [`Bytes::split`], but yields at most `n` views, the last one holding the rest.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// let header = Bytes::from("Host: example.com:8080");
+    /// let mut parts = header.splitn(2, b':');
+    /// assert_eq!(parts.next().unwrap(), "Host");
+    /// assert_eq!(parts.next().unwrap(), " example.com:8080");
+    /// ```
+    pub fn splitn<P: BytePattern>(&self, n: usize, pat: P) -> SplitN<'b, P> {
+        SplitN {
+            inner: self.split(pat),
+            count: n,
+        }
+    }
+
+    /// Like [`Bytes::splitn`], starting from the end.
+    pub fn rsplitn<P: BytePattern>(&self, n: usize, pat: P) -> RSplitN<'b, P> {
+        RSplitN {
+            inner: self.split(pat),
+            count: n,
+        }
+    }
+}
+
+/// Returned by [`Bytes::chunks_exact`].
+#[derive(Clone, Debug)]
+pub struct ChunksExact<'b> {
+    rest: Bytes<'b>,
+    remainder: Bytes<'b>,
+    chunk_size: usize,
+}
+
+impl<'b> ChunksExact<'b> {
+    /// The trailing bytes that don't make up a whole chunk.
+    pub fn remainder(&self) -> &Bytes<'b> {
+        &self.remainder
+    }
+}
+
+impl<'b> Iterator for ChunksExact<'b> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (!self.rest.is_empty()).then(|| self.rest.split_to(self.chunk_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.rest.len() / self.chunk_size;
+        (n, Some(n))
+    }
+}
+
+impl<'b> DoubleEndedIterator for ChunksExact<'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (!self.rest.is_empty()).then(|| self.rest.split_off(self.rest.len() - self.chunk_size))
+    }
+}
+
+impl<'b> ExactSizeIterator for ChunksExact<'b> {}
+
+/// Returned by [`Bytes::array_chunks`].
+#[derive(Clone, Debug)]
+pub struct ArrayChunks<'b, const N: usize> {
+    inner: ChunksExact<'b>,
+}
+
+impl<'b, const N: usize> ArrayChunks<'b, N> {
+    /// The trailing bytes that don't make up a whole block.
+    pub fn remainder(&self) -> &Bytes<'b> {
+        self.inner.remainder()
+    }
+}
+
+impl<'b, const N: usize> Iterator for ArrayChunks<'b, N> {
+    type Item = [u8; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(to_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'b, const N: usize> DoubleEndedIterator for ArrayChunks<'b, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(to_array)
+    }
+}
+
+impl<'b, const N: usize> ExactSizeIterator for ArrayChunks<'b, N> {}
+
+/// Returned by [`Bytes::split`].
+#[derive(Clone, Debug)]
+pub struct Split<'b, P> {
+    rest: Bytes<'b>,
+    pat: P,
+    finished: bool,
+}
+
+impl<'b, P> Split<'b, P> {
+    fn finish(&mut self) -> Option<Bytes<'b>> {
+        if self.finished {
+            return None;
+        }
+
+        self.finished = true;
+        Some(mem::take(&mut self.rest))
+    }
+}
+
+impl<'b, P: BytePattern> Iterator for Split<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.rest.iter().position(|&b| self.pat.matches(b)) {
+            Some(i) => {
+                let head = self.rest.split_to(i);
+                self.rest.advance(1);
+                Some(head)
+            }
+            None => self.finish(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.rest.len() + 1))
+        }
+    }
+}
+
+impl<'b, P: BytePattern> DoubleEndedIterator for Split<'b, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.rest.iter().rposition(|&b| self.pat.matches(b)) {
+            Some(i) => {
+                let tail = self.rest.split_off(i + 1);
+                self.rest.truncate(i);
+                Some(tail)
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+/// Returned by [`Bytes::split_inclusive`].
+#[derive(Clone, Debug)]
+pub struct SplitInclusive<'b, P> {
+    rest: Bytes<'b>,
+    pat: P,
+}
+
+impl<'b, P: BytePattern> Iterator for SplitInclusive<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let end = self
+            .rest
+            .iter()
+            .position(|&b| self.pat.matches(b))
+            .map_or(self.rest.len(), |i| i + 1);
+        Some(self.rest.split_to(end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len();
+        (usize::from(len != 0), Some(len))
+    }
+}
+
+impl<'b, P: BytePattern> DoubleEndedIterator for SplitInclusive<'b, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, init) = self.rest.split_last()?;
+        let start = init
+            .iter()
+            .rposition(|&b| self.pat.matches(b))
+            .map_or(0, |i| i + 1);
+        Some(self.rest.split_off(start))
+    }
+}
+
+/// Returned by [`Bytes::split_terminator`].
+#[derive(Clone, Debug)]
+pub struct SplitTerminator<'b, P> {
+    inner: Split<'b, P>,
+}
+
+impl<'b, P: BytePattern> Iterator for SplitTerminator<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'b, P: BytePattern> DoubleEndedIterator for SplitTerminator<'b, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// Returned by [`Bytes::split_ascii_whitespace`].
+#[derive(Clone, Debug)]
+pub struct SplitAsciiWhitespace<'b> {
+    inner: Split<'b, fn(u8) -> bool>,
+}
+
+impl<'b> Iterator for SplitAsciiWhitespace<'b> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find(|token| !token.is_empty())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'b> DoubleEndedIterator for SplitAsciiWhitespace<'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().rfind(|token| !token.is_empty())
+    }
+}
+
+/// Returned by [`Bytes::lines_terminated`].
+#[derive(Clone, Debug)]
+pub struct LinesTerminated<'b> {
+    inner: SplitInclusive<'b, u8>,
+}
+
+fn strip_line_ending(mut line: Bytes<'_>) -> (Bytes<'_>, bool) {
+    let terminated = line.ends_with(b"\n");
+    if terminated {
+        let ending = if line.ends_with(b"\r\n") { 2 } else { 1 };
+        line.truncate(line.len() - ending);
+    }
+    (line, terminated)
+}
+
+impl<'b> Iterator for LinesTerminated<'b> {
+    type Item = (Bytes<'b>, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(strip_line_ending)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'b> DoubleEndedIterator for LinesTerminated<'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(strip_line_ending)
+    }
+}
+
+/// Returned by [`Bytes::lines`].
+#[derive(Clone, Debug)]
+pub struct Lines<'b> {
+    inner: LinesTerminated<'b>,
+}
+
+impl<'b> Iterator for Lines<'b> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(line, _)| line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'b> DoubleEndedIterator for Lines<'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(line, _)| line)
+    }
+}
+
+/// Returned by [`Bytes::rsplit`].
+#[derive(Clone, Debug)]
+pub struct RSplit<'b, P> {
+    inner: Split<'b, P>,
+}
+
+impl<'b, P: BytePattern> Iterator for RSplit<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'b, P: BytePattern> DoubleEndedIterator for RSplit<'b, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// Returned by [`Bytes::splitn`].
+#[derive(Clone, Debug)]
+pub struct SplitN<'b, P> {
+    inner: Split<'b, P>,
+    count: usize,
+}
+
+impl<'b, P: BytePattern> Iterator for SplitN<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let upper = upper.map_or(self.count, |n| n.min(self.count));
+        (lower.min(self.count), Some(upper))
+    }
+}
+
+/// Returned by [`Bytes::rsplitn`].
+#[derive(Clone, Debug)]
+pub struct RSplitN<'b, P> {
+    inner: Split<'b, P>,
+    count: usize,
+}
+
+impl<'b, P: BytePattern> Iterator for RSplitN<'b, P> {
+    type Item = Bytes<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next_back()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let upper = upper.map_or(self.count, |n| n.min(self.count));
+        (lower.min(self.count), Some(upper))
+    }
+}
+
+fn to_array<const N: usize>(chunk: Bytes<'_>) -> [u8; N] {
+    *chunk.as_fixed().expect("chunk has exactly N bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn chunks_exact() {
+        let b = Bytes::from("abcdefgh");
+        let mut chunks = b.chunks_exact(3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder(), "gh");
+        assert_eq!(chunks.next_back().unwrap(), "def");
+        assert_eq!(chunks.collect::<Vec<_>>(), ["abc"]);
+        assert_eq!(Bytes::from("ab").chunks_exact(3).count(), 0);
+
+        let mut blocks = b.array_chunks::<4>();
+        assert!(blocks.remainder().is_empty());
+        assert_eq!(blocks.next(), Some(*b"abcd"));
+        assert_eq!(blocks.next(), Some(*b"efgh"));
+        assert_eq!(blocks.next(), None);
+    }
+
+    #[test]
+    fn split() {
+        let b = Bytes::from("/usr/local/bin/");
+        assert_eq!(
+            b.split(b'/').collect::<Vec<_>>(),
+            ["", "usr", "local", "bin", ""]
+        );
+        assert_eq!(b.rsplit(b'/').nth(1).unwrap(), "bin");
+        assert_eq!(Bytes::new().split(b'/').collect::<Vec<_>>(), [""]);
+
+        let mut parts = b.split(b'/');
+        assert_eq!(parts.next_back().unwrap(), "");
+        assert_eq!(parts.next().unwrap(), "");
+        assert_eq!(parts.next_back().unwrap(), "bin");
+        assert_eq!(parts.collect::<Vec<_>>(), ["usr", "local"]);
+
+        let path = Bytes::from("a/b/c");
+        assert_eq!(path.splitn(2, b'/').collect::<Vec<_>>(), ["a", "b/c"]);
+        assert_eq!(path.rsplitn(2, b'/').collect::<Vec<_>>(), ["c", "a/b"]);
+        assert_eq!(path.splitn(5, b'/').count(), 3);
+        assert_eq!(path.rsplitn(0, b'/').count(), 0);
+    }
+
+    #[test]
+    fn split_inclusive() {
+        let records = Bytes::from("+OK\r\n-ERR\r\n:1");
+        assert_eq!(
+            records.split_inclusive(b'\n').collect::<Vec<_>>(),
+            ["+OK\r\n", "-ERR\r\n", ":1"]
+        );
+        assert_eq!(
+            records.split_inclusive(b'\n').rev().collect::<Vec<_>>(),
+            [":1", "-ERR\r\n", "+OK\r\n"]
+        );
+        assert_eq!(Bytes::from("a\n\n").split_inclusive(b'\n').count(), 2);
+        assert_eq!(Bytes::new().split_inclusive(b'\n').count(), 0);
+    }
+
+    #[test]
+    fn split_ascii_whitespace() {
+        let b = Bytes::from(" MAIL FROM:<a@b.c>\r\n");
+        assert_eq!(
+            b.split_ascii_whitespace().collect::<Vec<_>>(),
+            ["MAIL", "FROM:<a@b.c>"]
+        );
+        assert_eq!(
+            b.split_ascii_whitespace().next_back().unwrap(),
+            "FROM:<a@b.c>"
+        );
+        assert_eq!(Bytes::from(" \t\n").split_ascii_whitespace().count(), 0);
+    }
+
+    #[test]
+    fn lines() {
+        let b = Bytes::from("GET / HTTP/1.1\r\nHost: a\n\r\n\rtrailing\r");
+        assert_eq!(
+            b.lines().collect::<Vec<_>>(),
+            ["GET / HTTP/1.1", "Host: a", "", "\rtrailing\r"]
+        );
+        assert_eq!(b.lines().next_back().unwrap(), "\rtrailing\r");
+        assert_eq!(Bytes::from("a\n").lines().collect::<Vec<_>>(), ["a"]);
+        assert_eq!(Bytes::from("\n").lines().collect::<Vec<_>>(), [""]);
+        assert_eq!(Bytes::new().lines().count(), 0);
+
+        let mut lines = Bytes::from("done\npartial").lines_terminated();
+        assert_eq!(lines.next_back(), Some((Bytes::from("partial"), false)));
+        assert_eq!(lines.next(), Some((Bytes::from("done"), true)));
+    }
+
+    #[test]
+    fn split_terminator() {
+        let b = Bytes::from("a;b;");
+        assert_eq!(b.split_terminator(b';').collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(
+            b.split_terminator(b';').rev().collect::<Vec<_>>(),
+            ["b", "a"]
+        );
+        assert_eq!(Bytes::from("a;;").split_terminator(b';').count(), 2);
+        assert_eq!(
+            Bytes::from(";").split_terminator(b';').collect::<Vec<_>>(),
+            [""]
+        );
+        assert_eq!(Bytes::new().split_terminator(b';').count(), 0);
+    }
+}
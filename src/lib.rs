@@ -1,33 +1,185 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use alloc::{string::String, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, string::String, sync::Arc, vec::Vec};
 pub use bytes::{Buf, BufMut, BytesMut};
 use core::{
+    array::TryFromSliceError,
     borrow::Borrow,
     cmp,
+    ffi::CStr,
     fmt::{self, Debug},
+    hash::{Hash, Hasher},
     iter::FromIterator,
     marker::PhantomData,
-    mem::transmute,
+    mem::{self, transmute},
     ops::{Deref, RangeBounds},
+    str::{self, Utf8Error},
 };
 
-#[derive(Clone, Default, Hash)]
+#[cfg(feature = "bincode")]
+mod bincode;
+mod buf;
+mod builder;
+#[cfg(any(feature = "zstd", feature = "deflate"))]
+mod compress;
+#[cfg(feature = "crypto-encodings")]
+mod crypto_encodings;
+#[cfg(feature = "encoding_rs")]
+mod encoding;
+mod escape;
+pub mod fallible;
+mod ffi;
+#[cfg(any(feature = "alloy-primitives", feature = "primitive-types"))]
+mod fixed_hash;
+#[cfg(feature = "instrument")]
+pub mod instrument;
+#[cfg(not(feature = "instrument"))]
+mod instrument {
+    #[inline(always)]
+    pub(crate) fn record_copy(_len: usize) {}
+
+    #[inline(always)]
+    pub(crate) fn record_share(_len: usize) {}
+}
+mod hex;
+mod interner;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
+mod local;
+mod macros;
+#[cfg(feature = "minicbor")]
+mod minicbor;
+mod owner;
+mod pattern;
+#[cfg(feature = "percent-encoding")]
+mod percent;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "redb")]
+mod redb;
+#[cfg(feature = "regex")]
+pub mod regex;
+#[cfg(feature = "rocksdb")]
+pub mod rocksdb;
+#[cfg(feature = "schemars")]
+mod schema;
+mod segmented;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(all(unix, feature = "shm"))]
+mod shm;
+#[cfg(feature = "sled")]
+mod sled;
+#[cfg(feature = "ssz")]
+pub mod ssz;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(all(target_os = "linux", feature = "tokio-uring"))]
+mod uring;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "web")]
+mod web;
+#[cfg(feature = "yoke")]
+mod yoke;
+
+pub use buf::{BufMutExt, LifetimedBuf};
+pub use builder::BytesBuilder;
+#[cfg(feature = "std")]
+pub use bytes::buf::{Reader, Writer};
+pub use escape::EscapeAscii;
+pub use fallible::OutOfBounds;
+pub use ffi::RawBytes;
+pub use interner::{BytesInterner, InternerStats};
+pub use local::LocalBytes;
+pub use owner::OwnedBytes;
+pub use pattern::BytePattern;
+#[cfg(feature = "std")]
+pub use pool::{BytesPool, PoolStats, PooledBuf};
+#[cfg(feature = "pyo3")]
+pub use python::PyBytesView;
+pub use segmented::SegmentedBytes;
+#[cfg(all(unix, feature = "shm"))]
+pub use shm::SharedMemory;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::Literal;
+
+    pub mod hex {
+        pub use crate::hex::{decode_array, decoded_len};
+    }
+}
+
+#[derive(Clone, Default)]
 pub struct Bytes<'b> {
     inner: bytes::Bytes,
+    origin: Origin,
     _marker: PhantomData<&'b ()>,
 }
 
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+enum Origin {
+    // `inner` points into memory borrowed for `'b` rather than owning it
+    Borrowed,
+    #[default]
+    Static,
+    Owned,
+}
+
+/// Where the data of a [`Bytes`] lives, as reported by [`Bytes::storage_kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StorageKind {
+    /// Borrowed for the lifetime of the handle.
+    Borrowed,
+    /// Static data, e.g. from [`Bytes::from_static`].
+    Static,
+    /// Owned storage not shared with any other handle; dropping this handle frees it.
+    Unique,
+    /// Owned storage that other handles may still refer to. Storage adopted through
+    /// [`Bytes::from_owner`] or `bytes::Bytes::from_static` is always reported as shared.
+    ///
+    /// `bytes::Bytes` doesn't expose its reference count, so there is no way to tell how many
+    /// other handles share the storage, only that it isn't known to be unique.
+    Shared,
+}
+
 impl<'b> Bytes<'b> {
     pub const fn new() -> Self {
         Self {
             inner: bytes::Bytes::new(),
+            origin: Origin::Static,
             _marker: PhantomData,
         }
     }
 
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// const MAGIC: Bytes<'static> = Bytes::from_static(b"\x7fELF");
+    /// assert_eq!(MAGIC.len(), 4);
+    /// ```
+    pub const fn from_static(bytes: &'static [u8]) -> Self {
+        Self {
+            inner: bytes::Bytes::from_static(bytes),
+            origin: Origin::Static,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Allocates a buffer of `len` copies of `byte`.
+    pub fn filled(byte: u8, len: usize) -> Self {
+        alloc::vec![byte; len].into()
+    }
+
     pub fn len(&self) -> usize {
         self.inner.len()
     }
@@ -37,21 +189,101 @@ impl<'b> Bytes<'b> {
     }
 
     pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
-        self.inner.slice(range).into()
+        self.with_inner(self.inner.slice(range))
     }
 
     pub fn slice_ref(&self, subset: &[u8]) -> Self {
-        self.inner.slice_ref(subset).into()
+        self.with_inner(self.inner.slice_ref(subset))
+    }
+
+    /// Like [`Bytes::slice_ref`], but returns `None` instead of panicking when `subset` is not
+    /// contained in this buffer.
+    pub fn try_slice_ref(&self, subset: &[u8]) -> Option<Self> {
+        if subset.is_empty() {
+            return Some(Self::new());
+        }
+
+        let offset = self.subslice_offset(subset)?;
+        Some(self.slice(offset..offset + subset.len()))
+    }
+
+    /// Views the buffer as a fixed-size array, failing unless it is exactly `N` bytes long.
+    pub fn as_fixed<const N: usize>(&self) -> Result<&[u8; N], TryFromSliceError> {
+        self.as_slice().try_into()
+    }
+
+    /// Offset of `sub` within this buffer, if it points into it.
+    pub fn subslice_offset(&self, sub: &[u8]) -> Option<usize> {
+        let offset = (sub.as_ptr() as usize).checked_sub(self.as_ptr() as usize)?;
+        (offset <= self.len() && sub.len() <= self.len() - offset).then_some(offset)
     }
 
     #[must_use = "consider Bytes::truncate if you don't need the other half"]
     pub fn split_off(&mut self, at: usize) -> Self {
-        self.inner.split_off(at).into()
+        let inner = self.inner.split_off(at);
+        self.with_inner(inner)
     }
 
     #[must_use = "consider Bytes::advance if you don't need the other half"]
     pub fn split_to(&mut self, at: usize) -> Self {
-        self.inner.split_to(at).into()
+        let inner = self.inner.split_to(at);
+        self.with_inner(inner)
+    }
+
+    /// Splits into at most `n` views of near-equal length, without copying. Empty views are
+    /// skipped, so fewer than `n` are returned when the buffer is shorter than `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split_into(&self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "cannot split into zero parts");
+
+        let mut start = 0;
+        (1..=n)
+            .map(|i| Self::partition_point(self.len(), n, i))
+            .filter_map(|end| {
+                let part = (end > start).then(|| self.slice(start..end));
+                start = end;
+                part
+            })
+            .collect()
+    }
+
+    /// Like [`Bytes::split_into`], but moves each split point forward to just past the next
+    /// `delimiter`, so records spanning a boundary stay whole. May return fewer than `n` views.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split_into_aligned(&self, n: usize, delimiter: u8) -> Vec<Self> {
+        assert!(n > 0, "cannot split into zero parts");
+
+        let len = self.len();
+        let mut parts = Vec::with_capacity(cmp::min(n, len));
+        let mut start = 0;
+        for i in 1..=n {
+            if start == len {
+                break;
+            }
+
+            let end = if i == n {
+                len
+            } else {
+                let from = cmp::max(Self::partition_point(len, n, i).saturating_sub(1), start);
+                self[from..]
+                    .iter()
+                    .position(|&b| b == delimiter)
+                    .map_or(len, |pos| from + pos + 1)
+            };
+            parts.push(self.slice(start..end));
+            start = end;
+        }
+        parts
+    }
+
+    fn partition_point(len: usize, n: usize, i: usize) -> usize {
+        i * (len / n) + cmp::min(i, len % n)
     }
 
     #[inline]
@@ -64,11 +296,241 @@ impl<'b> Bytes<'b> {
         self.inner.clear()
     }
 
+    /// Validates the data as UTF-8. The error reports how many leading bytes were valid.
+    ///
+    /// With the `simd` feature, valid input is checked with SIMD instructions where the CPU
+    /// supports them. Invalid input is then checked again to locate the error.
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        #[cfg(feature = "simd")]
+        if let Ok(s) = simdutf8::basic::from_utf8(self) {
+            return Ok(s);
+        }
+
+        str::from_utf8(self)
+    }
+
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        #[cfg(feature = "simd")]
+        if let Ok(s) = simdutf8::basic::from_utf8(self) {
+            return Cow::Borrowed(s);
+        }
+
+        String::from_utf8_lossy(self)
+    }
+
+    pub fn eq_ignore_ascii_case(&self, other: impl AsRef<[u8]>) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other.as_ref())
+    }
+
+    pub fn trim_ascii_start(&self) -> Self {
+        self.slice_ref(self.as_ref().trim_ascii_start())
+    }
+
+    pub fn trim_ascii_end(&self) -> Self {
+        self.slice_ref(self.as_ref().trim_ascii_end())
+    }
+
+    pub fn trim_ascii(&self) -> Self {
+        self.slice_ref(self.as_ref().trim_ascii())
+    }
+
+    /// Strips leading bytes matching `pat`, e.g. zero padding or repeated separators.
+    pub fn trim_start_matches<P: BytePattern>(&self, mut pat: P) -> Self {
+        let start = self
+            .iter()
+            .position(|&b| !pat.matches(b))
+            .unwrap_or(self.len());
+        self.slice(start..)
+    }
+
+    /// Strips trailing bytes matching `pat`.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// let field = Bytes::from(&b"NAME\0\0\0\0"[..]);
+    /// assert_eq!(field.trim_end_matches(0), "NAME");
+    /// ```
+    pub fn trim_end_matches<P: BytePattern>(&self, mut pat: P) -> Self {
+        let end = self
+            .iter()
+            .rposition(|&b| !pat.matches(b))
+            .map_or(0, |i| i + 1);
+        self.slice(..end)
+    }
+
+    /// Strips leading and trailing bytes matching `pat`.
+    pub fn trim_matches<P: BytePattern>(&self, mut pat: P) -> Self {
+        let Some(start) = self.iter().position(|&b| !pat.matches(b)) else {
+            return self.slice(self.len()..);
+        };
+        let end = self.iter().rposition(|&b| !pat.matches(b)).unwrap_or(start);
+        self.slice(start..=end)
+    }
+
+    /// Allocates a buffer holding the data repeated `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length would overflow.
+    pub fn repeat(&self, n: usize) -> Self {
+        self.as_ref().repeat(n).into()
+    }
+
+    /// Length of the longest common prefix with `other`, compared a word at a time.
+    pub fn common_prefix_len(&self, other: &[u8]) -> usize {
+        const WORD: usize = mem::size_of::<u64>();
+
+        let this: &[u8] = self;
+        let len = cmp::min(this.len(), other.len());
+
+        let mut i = 0;
+        for (a, b) in this[..len]
+            .chunks_exact(WORD)
+            .zip(other[..len].chunks_exact(WORD))
+        {
+            let diff = u64::from_le_bytes(a.try_into().unwrap())
+                ^ u64::from_le_bytes(b.try_into().unwrap());
+            if diff != 0 {
+                return i + (diff.trailing_zeros() / 8) as usize;
+            }
+            i += WORD;
+        }
+
+        i + this[i..len]
+            .iter()
+            .zip(&other[i..len])
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    pub fn common_prefix(&self, other: &[u8]) -> Self {
+        self.slice(..self.common_prefix_len(other))
+    }
+
+    /// Returns a lowercased copy, or a cheap clone if there is nothing to change.
+    pub fn to_ascii_lowercase(&self) -> Self {
+        if self.iter().any(u8::is_ascii_uppercase) {
+            instrument::record_copy(self.len());
+            self.as_ref().to_ascii_lowercase().into()
+        } else {
+            instrument::record_share(self.len());
+            self.clone()
+        }
+    }
+
+    /// Returns an uppercased copy, or a cheap clone if there is nothing to change.
+    pub fn to_ascii_uppercase(&self) -> Self {
+        if self.iter().any(u8::is_ascii_lowercase) {
+            instrument::record_copy(self.len());
+            self.as_ref().to_ascii_uppercase().into()
+        } else {
+            instrument::record_share(self.len());
+            self.clone()
+        }
+    }
+
+    pub fn storage_kind(&self) -> StorageKind {
+        match self.origin {
+            Origin::Borrowed => StorageKind::Borrowed,
+            Origin::Static => StorageKind::Static,
+            Origin::Owned if self.inner.is_unique() => StorageKind::Unique,
+            Origin::Owned => StorageKind::Shared,
+        }
+    }
+
+    /// Whether dropping this handle frees the underlying storage.
+    ///
+    /// This is the closest available answer to a reference count: `bytes::Bytes` doesn't
+    /// expose one, so this crate has no `ref_count` accessor.
+    pub fn is_unique(&self) -> bool {
+        self.storage_kind() == StorageKind::Unique
+    }
+
+    /// The underlying `bytes::Bytes`, or `None` if the data is borrowed.
+    ///
+    /// Borrowed data is never exposed this way, since a `bytes::Bytes` pointing at it could be
+    /// cloned and kept past `'b`. Use [`Bytes::into_shared`] to get one regardless.
+    pub fn as_inner(&self) -> Option<&bytes::Bytes> {
+        (self.origin != Origin::Borrowed).then_some(&self.inner)
+    }
+
+    /// Converts into a `bytes::Bytes` for APIs that take one.
+    ///
+    /// **This copies the data if it is borrowed**, as it must stay valid past `'b`. Owned and
+    /// static data are passed through without copying.
+    pub fn into_shared(self) -> bytes::Bytes {
+        match self.as_borrowed() {
+            Some(data) => {
+                instrument::record_copy(data.len());
+                bytes::Bytes::copy_from_slice(data)
+            }
+            None => {
+                instrument::record_share(self.len());
+                self.inner
+            }
+        }
+    }
+
+    fn with_inner(&self, inner: bytes::Bytes) -> Self {
+        Self {
+            inner,
+            origin: self.origin,
+            _marker: PhantomData,
+        }
+    }
+
+    fn as_borrowed(&self) -> Option<&'b [u8]> {
+        // SAFETY: borrowed data is valid for `'b` regardless of how long `self` lives
+        (self.origin == Origin::Borrowed)
+            .then(|| unsafe { transmute::<&[u8], &'b [u8]>(self.inner.as_ref()) })
+    }
+
     fn as_slice(&'b self) -> &'b [u8] {
         self.inner.borrow()
     }
 }
 
+impl<'b> Bytes<'b> {
+    /// Returns the first byte without consuming it.
+    pub fn peek_u8(&self) -> Option<u8> {
+        self.first().copied()
+    }
+
+    /// Returns the first `N` bytes without consuming them.
+    pub fn peek_array<const N: usize>(&self) -> Option<[u8; N]> {
+        self.get(..N)?.try_into().ok()
+    }
+
+    /// Returns a view of the first `len` bytes without consuming them.
+    pub fn peek_slice(&self, len: usize) -> Option<Self> {
+        (len <= self.len()).then(|| self.slice(..len))
+    }
+}
+
+macro_rules! peek_impls {
+    ($($name:ident: $ty:ty = $from:ident, $order:literal;)*) => {
+        impl<'b> Bytes<'b> {
+            $(
+                #[doc = concat!(
+                    "Reads a ", $order, " `", stringify!($ty), "` from the front without consuming it."
+                )]
+                pub fn $name(&self) -> Option<$ty> {
+                    self.peek_array().map(<$ty>::$from)
+                }
+            )*
+        }
+    };
+}
+
+peek_impls! {
+    peek_u16: u16 = from_be_bytes, "big-endian";
+    peek_u16_le: u16 = from_le_bytes, "little-endian";
+    peek_u32: u32 = from_be_bytes, "big-endian";
+    peek_u32_le: u32 = from_le_bytes, "little-endian";
+    peek_u64: u64 = from_be_bytes, "big-endian";
+    peek_u64_le: u64 = from_le_bytes, "little-endian";
+}
+
 impl<'b> Buf for Bytes<'b> {
     fn remaining(&self) -> usize {
         self.inner.remaining()
@@ -81,6 +543,20 @@ impl<'b> Buf for Bytes<'b> {
     fn advance(&mut self, cnt: usize) {
         self.inner.advance(cnt)
     }
+
+    /// Splits off the front without copying when the buffer owns its storage. Borrowed data
+    /// cannot outlive `'b`, so it is copied into a fresh `bytes::Bytes` instead.
+    fn copy_to_bytes(&mut self, len: usize) -> bytes::Bytes {
+        if self.origin == Origin::Borrowed {
+            instrument::record_copy(len);
+            let out = bytes::Bytes::copy_from_slice(&self[..len]);
+            self.advance(len);
+            out
+        } else {
+            instrument::record_share(len);
+            self.inner.split_to(len)
+        }
+    }
 }
 
 impl<'b> Debug for Bytes<'b> {
@@ -89,6 +565,36 @@ impl<'b> Debug for Bytes<'b> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<'b> defmt::Format for Bytes<'b> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        const MAX_SHOWN: usize = 32;
+
+        if self.len() <= MAX_SHOWN {
+            defmt::write!(f, "Bytes({=usize}, {=[u8]:02x})", self.len(), self.as_ref());
+        } else {
+            defmt::write!(
+                f,
+                "Bytes({=usize}, {=[u8]:02x}..)",
+                self.len(),
+                &self[..MAX_SHOWN]
+            );
+        }
+    }
+}
+
+/// Shrinks by truncation and by simplifying individual bytes.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Bytes<'static> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Vec::<u8>::arbitrary(g).into()
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.to_vec().shrink().map(Self::from))
+    }
+}
+
 impl<'b> Deref for Bytes<'b> {
     type Target = [u8];
 
@@ -109,12 +615,21 @@ impl<'b> Borrow<[u8]> for Bytes<'b> {
     }
 }
 
+impl<'a, 'b> TryFrom<&'a Bytes<'b>> for &'a str {
+    type Error = Utf8Error;
+
+    fn try_from(b: &'a Bytes<'b>) -> Result<Self, Self::Error> {
+        b.to_str()
+    }
+}
+
 impl<'b> From<&'b [u8]> for Bytes<'b> {
     fn from(raw: &'b [u8]) -> Self {
         // SAFETY: normally unsound, but we just move the lifetime from slice to struct itself
-        let s = unsafe { transmute(raw) };
+        let s = unsafe { transmute::<&'b [u8], &'static [u8]>(raw) };
         Bytes {
             inner: bytes::Bytes::from_static(s),
+            origin: Origin::Borrowed,
             _marker: PhantomData,
         }
     }
@@ -132,15 +647,66 @@ impl<'b> From<&'b str> for Bytes<'b> {
     }
 }
 
+impl<'b> From<&'b Vec<u8>> for Bytes<'b> {
+    fn from(v: &'b Vec<u8>) -> Self {
+        v.as_slice().into()
+    }
+}
+
+impl<'b> From<&'b String> for Bytes<'b> {
+    fn from(s: &'b String) -> Self {
+        s.as_bytes().into()
+    }
+}
+
+/// Borrows the bytes without the nul terminator.
+impl<'b> From<&'b CStr> for Bytes<'b> {
+    fn from(s: &'b CStr) -> Self {
+        s.to_bytes().into()
+    }
+}
+
+/// Borrows the platform's encoded representation, which is arbitrary bytes on Unix and
+/// an unspecified superset of UTF-8 elsewhere.
+#[cfg(feature = "std")]
+impl<'b> From<&'b std::ffi::OsStr> for Bytes<'b> {
+    fn from(s: &'b std::ffi::OsStr) -> Self {
+        s.as_encoded_bytes().into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'b> From<&'b std::path::Path> for Bytes<'b> {
+    fn from(path: &'b std::path::Path) -> Self {
+        path.as_os_str().into()
+    }
+}
+
 impl<'b> From<bytes::Bytes> for Bytes<'b> {
     fn from(inner: bytes::Bytes) -> Self {
         Self {
             inner,
+            origin: Origin::Owned,
             _marker: PhantomData,
         }
     }
 }
 
+/// Freezes a buffer filled through [`BufMut`] or, with the `std` feature, its
+/// `writer` adapter.
+///
+/// ```
+/// # use lifetimed_bytes::{BufMut, Bytes, BytesMut};
+/// let mut buf = BytesMut::new();
+/// buf.put_u16(0xcafe);
+/// assert_eq!(Bytes::from(buf), &[0xca, 0xfe]);
+/// ```
+impl<'b> From<BytesMut> for Bytes<'b> {
+    fn from(buf: BytesMut) -> Self {
+        buf.freeze().into()
+    }
+}
+
 impl<'b> From<Vec<u8>> for Bytes<'b> {
     fn from(v: Vec<u8>) -> Self {
         bytes::Bytes::from(v).into()
@@ -224,6 +790,40 @@ forward_impls!(str);
 forward_impls!(Vec<u8>);
 forward_impls!(String);
 
+// For types that `bytes::Bytes` can't be compared with directly; compares contents as slices.
+macro_rules! slice_impls {
+    ($($lt:lifetime)? => $t:ty) => {
+        impl<'b, $($lt)?> PartialEq<$t> for Bytes<'b> {
+            fn eq(&self, other: &$t) -> bool {
+                self.as_slice() == &other[..]
+            }
+        }
+
+        impl<'b, $($lt)?> PartialEq<Bytes<'b>> for $t {
+            fn eq(&self, other: &Bytes<'b>) -> bool {
+                &self[..] == other.as_slice()
+            }
+        }
+
+        impl<'b, $($lt)?> PartialOrd<$t> for Bytes<'b> {
+            fn partial_cmp(&self, other: &$t) -> Option<cmp::Ordering> {
+                PartialOrd::partial_cmp(self.as_slice(), &other[..])
+            }
+        }
+
+        impl<'b, $($lt)?> PartialOrd<Bytes<'b>> for $t {
+            fn partial_cmp(&self, other: &Bytes<'b>) -> Option<cmp::Ordering> {
+                PartialOrd::partial_cmp(&self[..], other.as_slice())
+            }
+        }
+    };
+}
+
+slice_impls!('c => Cow<'c, [u8]>);
+slice_impls!(=> Arc<[u8]>);
+slice_impls!(=> Box<[u8]>);
+slice_impls!(=> BytesMut);
+
 impl<'a, 'b> PartialEq<Bytes<'a>> for Bytes<'b> {
     fn eq(&self, other: &Bytes<'a>) -> bool {
         PartialEq::eq(&self.inner, other)
@@ -302,6 +902,12 @@ where
     }
 }
 
+impl<'b> Hash for Bytes<'b> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
 impl<'b> Eq for Bytes<'b> {}
 impl<'b> Ord for Bytes<'b> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
@@ -320,6 +926,212 @@ impl<'b> From<alloc::borrow::Cow<'b, [u8]>> for Bytes<'b> {
 
 #[cfg(test)]
 pub mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8() {
+        let b = Bytes::from(b"h\xc3\xa9\xff");
+        let err = b.to_str().unwrap_err();
+        assert_eq!(err.valid_up_to(), 3);
+        assert_eq!(b.to_string_lossy(), "hé\u{fffd}");
+        assert_eq!(<&str>::try_from(&b.slice(..3)).unwrap(), "hé");
+    }
+
+    #[test]
+    fn ascii() {
+        let b = Bytes::from(" \tContent-Length \r\n");
+        assert_eq!(b.trim_ascii_start(), "Content-Length \r\n");
+        assert_eq!(b.trim_ascii_end(), " \tContent-Length");
+        assert!(b.trim_ascii().eq_ignore_ascii_case("content-length"));
+        assert!(Bytes::from("  ").trim_ascii().is_empty());
+    }
+
+    #[test]
+    fn trim_matches() {
+        let b = Bytes::from("--==value==--");
+        assert_eq!(b.trim_start_matches(b'-'), "==value==--");
+        assert_eq!(b.trim_end_matches(&b"-="[..]), "--==value");
+        assert_eq!(b.trim_matches(|c: u8| !c.is_ascii_alphabetic()), "value");
+        assert!(b.trim_matches(&b"-=value"[..]).is_empty());
+        assert!(Bytes::new().trim_end_matches(b'-').is_empty());
+    }
+
+    #[test]
+    fn common_prefix() {
+        let b = Bytes::from("user:1234:profile:name");
+        assert_eq!(b.common_prefix_len(b"user:1234:profile:email"), 18);
+        assert_eq!(b.common_prefix(b"user:1"), "user:1");
+        assert_eq!(b.common_prefix_len(b"user:1234:pro"), 13);
+        assert_eq!(b.common_prefix_len(b"x"), 0);
+        assert_eq!(b.common_prefix_len(&b), b.len());
+    }
+
+    #[test]
+    fn copy_to_bytes() {
+        let mut owned = Bytes::from(b"hello world".to_vec());
+        let ptr = owned.as_ptr();
+        let head = owned.copy_to_bytes(5);
+        assert_eq!(head, "hello");
+        assert_eq!(head.as_ptr(), ptr);
+
+        let v = b"hello world".to_vec();
+        let mut borrowed = Bytes::from(v.as_slice()).slice(6..);
+        let tail = borrowed.copy_to_bytes(5);
+        assert_eq!(tail, "world");
+        assert_ne!(tail.as_ptr(), v[6..].as_ptr());
+        assert!(borrowed.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writer() {
+        use std::io::Write;
+
+        let mut w = BytesMut::new().writer();
+        write!(w, "{}-{}", 1, 2).unwrap();
+        assert_eq!(Bytes::from(w.into_inner()), "1-2");
+    }
+
+    #[test]
+    fn storage_kind() {
+        let v = b"abc".to_vec();
+        assert_eq!(
+            Bytes::from(v.as_slice()).storage_kind(),
+            StorageKind::Borrowed
+        );
+        assert_eq!(
+            Bytes::from_static(b"abc").storage_kind(),
+            StorageKind::Static
+        );
+
+        let owned = Bytes::from(v.clone());
+        assert!(owned.is_unique());
+        let shared = owned.slice(1..);
+        assert_eq!(owned.storage_kind(), StorageKind::Shared);
+        drop(owned);
+        assert!(shared.is_unique());
+    }
+
+    #[test]
+    fn from_c_str() {
+        assert_eq!(Bytes::from(c"hello"), "hello");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_path() {
+        let path = std::path::Path::new("/etc/hosts");
+        assert_eq!(Bytes::from(path), "/etc/hosts");
+        assert_eq!(Bytes::from(path.file_name().unwrap()), "hosts");
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn arbitrary() {
+        fn split_concat(b: Bytes<'static>, at: usize) -> bool {
+            let mut head = b.clone();
+            let tail = head.split_off(at % (b.len() + 1));
+            [head.as_ref(), tail.as_ref()].concat() == b
+        }
+
+        quickcheck::quickcheck(split_concat as fn(Bytes<'static>, usize) -> bool);
+    }
+
+    #[test]
+    fn peek() {
+        let mut b = Bytes::from(b"\x01\x00\x02rest");
+        assert_eq!(b.peek_u8(), Some(1));
+        assert_eq!(b.peek_u16(), Some(0x0100));
+        assert_eq!(b.peek_u16_le(), Some(0x0001));
+        assert_eq!(b.peek_u64(), None);
+        assert_eq!(b.peek_slice(3).unwrap(), b"\x01\x00\x02");
+        assert_eq!(b.len(), 7);
+
+        b.advance(3);
+        assert_eq!(b.peek_array(), Some(*b"rest"));
+        assert_eq!(b.peek_slice(5), None);
+    }
+
+    #[test]
+    fn as_fixed() {
+        let b = Bytes::from(b"\x01\x02\x03\x04");
+        assert_eq!(b.as_fixed::<4>().unwrap(), &[1, 2, 3, 4]);
+        assert!(b.as_fixed::<2>().is_err());
+        assert_eq!(b.slice(2..).as_fixed().unwrap(), &[3, 4]);
+    }
+
+    #[test]
+    fn split_into() {
+        let b = Bytes::from("abcdefghij");
+        let parts = b.split_into(3);
+        assert_eq!(parts, ["abcd", "efg", "hij"]);
+        assert!(parts.iter().all(|p| b.subslice_offset(p).is_some()));
+        assert_eq!(Bytes::from("ab").split_into(4), ["a", "b"]);
+        assert!(Bytes::new().split_into(2).is_empty());
+
+        let lines = Bytes::from("one\ntwo\nthree\nfour\n");
+        assert_eq!(
+            lines.split_into_aligned(2, b'\n'),
+            ["one\ntwo\nthree\n", "four\n"]
+        );
+        assert_eq!(lines.split_into_aligned(4, b'\n').concat(), lines);
+        assert_eq!(
+            Bytes::from("no delimiter")
+                .split_into_aligned(3, b'\n')
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn from_borrowed_containers() {
+        let v = Vec::from([1, 2, 3]);
+        let s = String::from("text");
+        let (bv, bs) = (Bytes::from(&v), Bytes::from(&s));
+        assert_eq!(bv.storage_kind(), StorageKind::Borrowed);
+        assert_eq!(bv.as_ptr(), v.as_ptr());
+        assert_eq!(bs, "text");
+        assert_eq!(Bytes::from(&[0u8; 2]), [0, 0][..]);
+    }
+
+    #[test]
+    fn into_shared() {
+        let data = Vec::from(&b"borrowed"[..]);
+        let b = Bytes::from(&data);
+        assert!(b.as_inner().is_none());
+        let shared = b.into_shared();
+        assert_eq!(shared, data);
+        assert_ne!(shared.as_ptr(), data.as_ptr());
+
+        let owned = Bytes::from(Vec::from(&b"owned"[..]));
+        let ptr = owned.as_inner().unwrap().as_ptr();
+        assert_eq!(owned.into_shared().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn compare_owned() {
+        let b = Bytes::from("abc");
+        assert_eq!(b, Cow::Borrowed(&b"abc"[..]));
+        assert_eq!(Cow::<[u8]>::Owned(b"abc".to_vec()), b);
+        assert_eq!(b, Arc::<[u8]>::from(&b"abc"[..]));
+        assert_eq!(Box::<[u8]>::from(&b"abc"[..]), b);
+        assert_eq!(BytesMut::from("abc"), b);
+        let (greater, less) = (BytesMut::from("abd"), Box::<[u8]>::from(&b"ab"[..]));
+        assert!(b < greater);
+        assert!(less < b);
+    }
+
+    #[test]
+    fn subslice() {
+        let b = Bytes::from("key=value");
+        let value = &b[4..];
+        assert_eq!(b.subslice_offset(value), Some(4));
+        assert_eq!(b.try_slice_ref(value).unwrap(), "value");
+        assert_eq!(b.subslice_offset(&b[9..]), Some(9));
+        assert_eq!(b.try_slice_ref(b"value"), None);
+        assert_eq!(b.slice(..3).try_slice_ref(value), None);
+    }
+
     #[test]
     fn ui() {
         let t = trybuild::TestCases::new();
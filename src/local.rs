@@ -0,0 +1,266 @@
+use crate::Bytes;
+use alloc::{rc::Rc, vec::Vec};
+use bytes::Buf;
+use core::{
+    borrow::Borrow,
+    cmp,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::{Bound, Deref, RangeBounds},
+};
+
+#[derive(Clone)]
+enum Storage<'b> {
+    Borrowed(&'b [u8]),
+    Shared(Rc<dyn AsRef<[u8]>>),
+}
+
+/// Single-threaded counterpart of [`Bytes`] that shares owned data through a non-atomic [`Rc`].
+///
+/// Converting from [`Bytes`] never copies. Converting back copies owned data, since it cannot
+/// be sent across threads.
+#[derive(Clone)]
+pub struct LocalBytes<'b> {
+    storage: Storage<'b>,
+    start: usize,
+    end: usize,
+}
+
+impl<'b> LocalBytes<'b> {
+    pub const fn new() -> Self {
+        Self {
+            storage: Storage::Borrowed(&[]),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Shares `owner` without copying. It is dropped along with the last handle.
+    pub fn from_owner<T: AsRef<[u8]> + 'static>(owner: T) -> Self {
+        let end = owner.as_ref().len();
+        Self {
+            storage: Storage::Shared(Rc::new(owner)),
+            start: 0,
+            end,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.len();
+        let begin = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("out of range"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("out of range"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            begin <= end,
+            "range start must not be greater than end: {:?} <= {:?}",
+            begin,
+            end,
+        );
+        assert!(
+            end <= len,
+            "range end out of bounds: {:?} <= {:?}",
+            end,
+            len
+        );
+
+        Self {
+            storage: self.storage.clone(),
+            start: self.start + begin,
+            end: self.start + end,
+        }
+    }
+
+    #[must_use = "consider LocalBytes::truncate if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let other = self.slice(at..);
+        self.end = other.start;
+        other
+    }
+
+    #[must_use = "consider LocalBytes::advance if you don't need the other half"]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        let other = self.slice(..at);
+        self.start = other.end;
+        other
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.end = self.start + len;
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        let data = match &self.storage {
+            Storage::Borrowed(data) => data,
+            Storage::Shared(owner) => (**owner).as_ref(),
+        };
+        &data[self.start..self.end]
+    }
+}
+
+impl<'b> Default for LocalBytes<'b> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'b> Buf for LocalBytes<'b> {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.len(),
+            "cannot advance past `remaining`: {:?} <= {:?}",
+            cnt,
+            self.len(),
+        );
+        self.start += cnt;
+    }
+}
+
+impl<'b> Debug for LocalBytes<'b> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b\"{}\"", self.as_slice().escape_ascii())
+    }
+}
+
+impl<'b> Deref for LocalBytes<'b> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'b> AsRef<[u8]> for LocalBytes<'b> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'b> Borrow<[u8]> for LocalBytes<'b> {
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'b, T: AsRef<[u8]> + ?Sized> PartialEq<T> for LocalBytes<'b> {
+    fn eq(&self, other: &T) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+impl<'b> Eq for LocalBytes<'b> {}
+
+impl<'b> PartialOrd for LocalBytes<'b> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'b> Ord for LocalBytes<'b> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<'b> Hash for LocalBytes<'b> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<'b> From<&'b [u8]> for LocalBytes<'b> {
+    fn from(data: &'b [u8]) -> Self {
+        Self {
+            storage: Storage::Borrowed(data),
+            start: 0,
+            end: data.len(),
+        }
+    }
+}
+
+impl<'b> From<&'b str> for LocalBytes<'b> {
+    fn from(s: &'b str) -> Self {
+        s.as_bytes().into()
+    }
+}
+
+impl<'b> From<Vec<u8>> for LocalBytes<'b> {
+    fn from(v: Vec<u8>) -> Self {
+        Self::from_owner(v)
+    }
+}
+
+/// Never copies: borrowed data stays borrowed and owned data is shared as is.
+impl<'b> From<Bytes<'b>> for LocalBytes<'b> {
+    fn from(b: Bytes<'b>) -> Self {
+        match b.as_borrowed() {
+            Some(data) => data.into(),
+            None => Self::from_owner(b.inner),
+        }
+    }
+}
+
+/// Copies owned data; borrowed data stays borrowed.
+impl<'b> From<LocalBytes<'b>> for Bytes<'b> {
+    fn from(b: LocalBytes<'b>) -> Self {
+        match b.storage {
+            Storage::Borrowed(data) => data[b.start..b.end].into(),
+            Storage::Shared(_) => {
+                crate::instrument::record_copy(b.len());
+                b.as_slice().to_vec().into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_bytes() {
+        let owned = Bytes::from(b"hello world".to_vec());
+        let mut local = LocalBytes::from(owned.clone());
+        assert_eq!(local.as_ptr(), owned.as_ptr());
+
+        let hello = local.split_to(6);
+        assert_eq!(hello, b"hello ");
+        assert_eq!(local, "world");
+        assert_eq!(local.slice(1..=2), "or");
+
+        let data = b"borrowed".to_vec();
+        let b = Bytes::from(LocalBytes::from(Bytes::from(data.as_slice())));
+        assert_eq!(b.as_ptr(), data.as_ptr());
+    }
+}
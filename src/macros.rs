@@ -0,0 +1,38 @@
+/// Builds a `Bytes<'static>` over constant data from a byte string, string, or hex literal.
+///
+/// Invalid hex literals are rejected at compile time.
+///
+/// ```
+/// use lifetimed_bytes::{bytes, Bytes};
+///
+/// const MAGIC: Bytes<'static> = bytes!(hex "cafebabe");
+/// assert_eq!(MAGIC, [0xca, 0xfe, 0xba, 0xbe]);
+/// assert_eq!(bytes!(b"\x00\x01"), [0, 1]);
+/// assert_eq!(bytes!("PING"), "PING");
+/// ```
+#[macro_export]
+macro_rules! bytes {
+    (hex $s:literal) => {{
+        const DATA: [u8; $crate::__private::hex::decoded_len($s)] =
+            $crate::__private::hex::decode_array($s);
+        $crate::Bytes::from_static(&DATA)
+    }};
+    ($s:literal) => {
+        $crate::Bytes::from_static($crate::__private::Literal($s).as_bytes())
+    };
+}
+
+#[doc(hidden)]
+pub struct Literal<T>(pub T);
+
+impl Literal<&'static str> {
+    pub const fn as_bytes(self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<const N: usize> Literal<&'static [u8; N]> {
+    pub const fn as_bytes(self) -> &'static [u8] {
+        self.0
+    }
+}
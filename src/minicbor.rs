@@ -0,0 +1,72 @@
+//! minicbor support.
+//!
+//! `Bytes` encodes as a CBOR byte string. Decoding borrows definite-length strings from the
+//! input, and copies only indefinite-length ones, which arrive in chunks.
+
+use crate::Bytes;
+use alloc::vec::Vec;
+use minicbor::{
+    data::Type,
+    decode::{self, Decoder},
+    encode::{self, Encoder, Write},
+    Decode, Encode,
+};
+
+impl<'b, C> Encode<C> for Bytes<'b> {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.bytes(self)?.ok()
+    }
+}
+
+impl<'b, C> Decode<'b, C> for Bytes<'b> {
+    fn decode(d: &mut Decoder<'b>, _: &mut C) -> Result<Self, decode::Error> {
+        if d.datatype()? != Type::BytesIndef {
+            return d.bytes().map(Self::from);
+        }
+        let mut buf = Vec::new();
+        for chunk in d.bytes_iter()? {
+            buf.extend_from_slice(chunk?);
+        }
+        crate::instrument::record_copy(buf.len());
+        Ok(buf.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+
+    #[test]
+    fn round_trip() {
+        let buf = minicbor::to_vec(Bytes::from("payload")).unwrap();
+        assert_eq!(buf[0], 0x47);
+
+        let borrowed: Bytes = minicbor::decode(&buf).unwrap();
+        assert_eq!(borrowed, "payload");
+        assert_eq!(borrowed.storage_kind(), StorageKind::Borrowed);
+        assert_eq!(borrowed.as_ptr(), buf[1..].as_ptr());
+    }
+
+    #[test]
+    fn indefinite() {
+        let mut e = Encoder::new(Vec::new());
+        e.begin_bytes()
+            .unwrap()
+            .bytes(b"pay")
+            .unwrap()
+            .bytes(b"load")
+            .unwrap()
+            .end()
+            .unwrap();
+        let buf = e.into_writer();
+
+        let joined: Bytes = minicbor::decode(&buf).unwrap();
+        assert_eq!(joined, "payload");
+        assert_ne!(joined.storage_kind(), StorageKind::Borrowed);
+    }
+}
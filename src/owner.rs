@@ -0,0 +1,170 @@
+//! Adopting arbitrary owners as `Bytes` storage.
+
+use crate::Bytes;
+use core::{fmt, mem, ops::Deref};
+use stable_deref_trait::StableDeref;
+
+impl<'b> Bytes<'b> {
+    /// Shares `owner` without copying. It is dropped along with the last handle.
+    pub fn from_owner<T: AsRef<[u8]> + Send + 'static>(owner: T) -> Self {
+        bytes::Bytes::from_owner(owner).into()
+    }
+
+    /// Like [`Bytes::from_owner`], but hands `owner` to `on_drop` once the last handle is
+    /// dropped, e.g. to return the memory to a pool or note when it was actually reclaimed.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// # use std::sync::mpsc;
+    /// let (tx, rx) = mpsc::channel();
+    /// let b = Bytes::from_owner_with_drop(vec![1, 2, 3], move |v| tx.send(v).unwrap());
+    /// let tail = b.slice(1..);
+    /// drop(b);
+    /// assert!(rx.try_recv().is_err());
+    /// drop(tail);
+    /// assert_eq!(rx.try_recv().unwrap(), [1, 2, 3]);
+    /// ```
+    pub fn from_owner_with_drop<T, F>(owner: T, on_drop: F) -> Self
+    where
+        T: AsRef<[u8]> + Send + 'static,
+        F: FnOnce(T) + Send + 'static,
+    {
+        Self::from_owner(OnDrop {
+            owner: Some(owner),
+            on_drop: Some(on_drop),
+        })
+    }
+}
+
+/// An owner bundled with a [`Bytes`] view borrowing from it, so the two can be returned and
+/// stored together without tracking the owner's lifetime separately.
+///
+/// The owner is any [`StableDeref`] to `[u8]`, such as a `Vec<u8>`, a `Box<[u8]>` or a memory
+/// map, whose data stays put when the owner itself is moved.
+///
+/// ```
+/// # use lifetimed_bytes::OwnedBytes;
+/// fn load() -> OwnedBytes<Vec<u8>> {
+///     let file = b"MAGIC payload".to_vec();
+///     OwnedBytes::new(file).map(|b| b.slice(6..))
+/// }
+///
+/// let payload = load();
+/// assert_eq!(payload.bytes(), "payload");
+/// assert_eq!(payload.owner().len(), 13);
+/// ```
+pub struct OwnedBytes<O> {
+    // borrows from `owner`, which must outlive it
+    view: Bytes<'static>,
+    owner: O,
+}
+
+impl<O: StableDeref<Target = [u8]>> OwnedBytes<O> {
+    /// Views all of `owner`'s data.
+    pub fn new(owner: O) -> Self {
+        // SAFETY: `owner`'s data doesn't move with it, and `view` is only ever handed out
+        // with a lifetime bounded by a borrow of `self`
+        let data = unsafe { mem::transmute::<&[u8], &'static [u8]>(&owner) };
+        Self {
+            view: data.into(),
+            owner,
+        }
+    }
+
+    /// Replaces the view, typically with a slice of it.
+    pub fn map(self, f: impl for<'a> FnOnce(Bytes<'a>) -> Bytes<'a>) -> Self {
+        self.try_map(|b| Ok::<_, core::convert::Infallible>(f(b)))
+            .unwrap_or_else(|e| match e {})
+    }
+
+    /// Like [`OwnedBytes::map`], but `f` may fail, in which case the owner is returned along
+    /// with the error.
+    pub fn try_map<E>(
+        self,
+        f: impl for<'a> FnOnce(Bytes<'a>) -> Result<Bytes<'a>, E>,
+    ) -> Result<Self, (O, E)> {
+        let Self { view, owner } = self;
+        match f(view) {
+            Ok(view) => Ok(Self { view, owner }),
+            Err(e) => Err((owner, e)),
+        }
+    }
+}
+
+impl<O> OwnedBytes<O> {
+    /// The current view, borrowed from `self`.
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.view.clone()
+    }
+
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    pub fn into_owner(self) -> O {
+        self.owner
+    }
+}
+
+impl<O> Deref for OwnedBytes<O> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.view
+    }
+}
+
+impl<O> AsRef<[u8]> for OwnedBytes<O> {
+    fn as_ref(&self) -> &[u8] {
+        &self.view
+    }
+}
+
+impl<O> fmt::Debug for OwnedBytes<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.view, f)
+    }
+}
+
+struct OnDrop<T, F: FnOnce(T)> {
+    owner: Option<T>,
+    on_drop: Option<F>,
+}
+
+impl<T: AsRef<[u8]>, F: FnOnce(T)> AsRef<[u8]> for OnDrop<T, F> {
+    fn as_ref(&self) -> &[u8] {
+        self.owner.as_ref().map_or(&[], T::as_ref)
+    }
+}
+
+impl<T, F: FnOnce(T)> Drop for OnDrop<T, F> {
+    fn drop(&mut self) {
+        if let (Some(owner), Some(on_drop)) = (self.owner.take(), self.on_drop.take()) {
+            on_drop(owner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{boxed::Box, vec::Vec};
+
+    #[test]
+    fn owned_bytes() {
+        let owned = OwnedBytes::new(Box::<[u8]>::from(&b"key:value"[..]));
+        let start = owned.as_ptr();
+        let moved = [owned];
+        let [owned] = moved;
+        assert_eq!(owned.as_ptr(), start);
+
+        let value = owned.map(|b| b.slice(4..));
+        assert_eq!(value.bytes(), "value");
+        assert_eq!(&*value, b"value");
+
+        let err = OwnedBytes::new(Vec::from(&b"x"[..]))
+            .try_map(|b| if b.len() > 1 { Ok(b) } else { Err("short") })
+            .unwrap_err();
+        assert_eq!(err, (Vec::from(&b"x"[..]), "short"));
+    }
+}
@@ -0,0 +1,43 @@
+/// Something that matches single bytes: a byte, a set of bytes given as a slice or array, or a
+/// `FnMut(u8) -> bool` predicate.
+///
+/// ```
+/// # use lifetimed_bytes::Bytes;
+/// let b = Bytes::from("k1=v1;k2=v2");
+/// assert_eq!(b.split(b';').count(), 2);
+/// assert_eq!(b.split(&b"=;"[..]).count(), 4);
+/// assert_eq!(b.split(|c: u8| c.is_ascii_digit()).count(), 5);
+/// ```
+pub trait BytePattern {
+    fn matches(&mut self, byte: u8) -> bool;
+}
+
+impl BytePattern for u8 {
+    fn matches(&mut self, byte: u8) -> bool {
+        *self == byte
+    }
+}
+
+impl BytePattern for &[u8] {
+    fn matches(&mut self, byte: u8) -> bool {
+        self.contains(&byte)
+    }
+}
+
+impl<const N: usize> BytePattern for [u8; N] {
+    fn matches(&mut self, byte: u8) -> bool {
+        self.contains(&byte)
+    }
+}
+
+impl<const N: usize> BytePattern for &[u8; N] {
+    fn matches(&mut self, byte: u8) -> bool {
+        self.contains(&byte)
+    }
+}
+
+impl<F: FnMut(u8) -> bool> BytePattern for F {
+    fn matches(&mut self, byte: u8) -> bool {
+        self(byte)
+    }
+}
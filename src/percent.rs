@@ -0,0 +1,52 @@
+use crate::Bytes;
+use alloc::borrow::Cow;
+use percent_encoding::AsciiSet;
+
+impl<'b> Bytes<'b> {
+    /// Decodes `%XX` escapes, as in URL paths and query strings. Returns a view of the same
+    /// data when there is nothing to decode. Malformed escapes are kept as they are.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// assert_eq!(Bytes::from("caf%C3%A9%2").percent_decode(), "café%2");
+    /// ```
+    pub fn percent_decode(&self) -> Self {
+        match Cow::from(percent_encoding::percent_decode(self)) {
+            Cow::Borrowed(_) => self.clone(),
+            Cow::Owned(decoded) => decoded.into(),
+        }
+    }
+
+    /// Escapes every byte in `set` and every non-ASCII byte as `%XX`. Returns a view of the
+    /// same data when nothing needs escaping.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// # use percent_encoding::NON_ALPHANUMERIC;
+    /// assert_eq!(Bytes::from("a b").percent_encode(NON_ALPHANUMERIC), "a%20b");
+    /// ```
+    pub fn percent_encode(&self, set: &'static AsciiSet) -> Self {
+        match Cow::from(percent_encoding::percent_encode(self, set)) {
+            Cow::Borrowed(_) => self.clone(),
+            Cow::Owned(encoded) => encoded.into_bytes().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use percent_encoding::{CONTROLS, NON_ALPHANUMERIC};
+
+    #[test]
+    fn round_trip() {
+        let plain = Bytes::from("/static/app.js");
+        assert_eq!(plain.percent_decode().as_ptr(), plain.as_ptr());
+        assert_eq!(plain.percent_encode(CONTROLS).as_ptr(), plain.as_ptr());
+
+        let b = Bytes::from("q=rust lang&x=ü");
+        let encoded = b.percent_encode(NON_ALPHANUMERIC);
+        assert_eq!(encoded, "q%3Drust%20lang%26x%3D%C3%BC");
+        assert_eq!(encoded.percent_decode(), b);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::Bytes;
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    fmt, mem,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+use std::sync::Mutex;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Buffers waiting in the pool for reuse.
+    pub idle: usize,
+    /// Buffers handed out and not yet returned, including frozen ones still referenced.
+    pub in_use: usize,
+    /// Buffers that had to be freshly allocated.
+    pub allocated: u64,
+    /// Buffers served from the pool instead.
+    pub reused: u64,
+}
+
+struct Shared {
+    free: Mutex<Vec<Vec<u8>>>,
+    buffer_capacity: usize,
+    max_idle: usize,
+    in_use: AtomicUsize,
+    allocated: AtomicU64,
+    reused: AtomicU64,
+}
+
+impl Shared {
+    fn recycle(&self, mut buf: Vec<u8>) {
+        self.in_use.fetch_sub(1, Ordering::Relaxed);
+        if buf.capacity() < self.buffer_capacity {
+            return;
+        }
+
+        // callers may have grown it through `DerefMut`; don't let that stick to the pool
+        buf.clear();
+        buf.shrink_to(self.buffer_capacity);
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        if free.len() < self.max_idle {
+            free.push(buf);
+        }
+    }
+}
+
+/// Hands out fixed-capacity buffers and takes them back once the last [`Bytes`] frozen from
+/// them is dropped. Clones refer to the same pool.
+///
+/// Buffers grown past `buffer_capacity` while checked out are shrunk back before being reused.
+/// The pool saves the buffer allocation only: every [`PooledBuf::freeze`] still allocates the
+/// small header [`Bytes::from_owner`] needs.
+#[derive(Clone)]
+pub struct BytesPool {
+    shared: Arc<Shared>,
+}
+
+impl BytesPool {
+    /// Creates a pool of `buffer_capacity`-byte buffers keeping at most `max_idle` around.
+    pub fn new(buffer_capacity: usize, max_idle: usize) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                free: Mutex::new(Vec::new()),
+                buffer_capacity,
+                max_idle,
+                in_use: AtomicUsize::new(0),
+                allocated: AtomicU64::new(0),
+                reused: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    pub fn buffer_capacity(&self) -> usize {
+        self.shared.buffer_capacity
+    }
+
+    /// Takes an empty buffer from the pool, allocating one if none is idle.
+    pub fn get(&self) -> PooledBuf {
+        let shared = &self.shared;
+        let idle = shared.free.lock().unwrap_or_else(|e| e.into_inner()).pop();
+        let buf = match idle {
+            Some(buf) => {
+                shared.reused.fetch_add(1, Ordering::Relaxed);
+                buf
+            }
+            None => {
+                shared.allocated.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(shared.buffer_capacity)
+            }
+        };
+        shared.in_use.fetch_add(1, Ordering::Relaxed);
+
+        PooledBuf {
+            buf,
+            pool: Some(Arc::clone(shared)),
+        }
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        let shared = &self.shared;
+        PoolStats {
+            idle: shared.free.lock().unwrap_or_else(|e| e.into_inner()).len(),
+            in_use: shared.in_use.load(Ordering::Relaxed),
+            allocated: shared.allocated.load(Ordering::Relaxed),
+            reused: shared.reused.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl fmt::Debug for BytesPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BytesPool")
+            .field("buffer_capacity", &self.shared.buffer_capacity)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+/// A buffer checked out of a [`BytesPool`]. Fill it through `Vec` methods or [`BufMut`], then
+/// [`freeze`](PooledBuf::freeze) it. Dropping it unfrozen returns it to the pool right away.
+///
+/// [`BufMut`]: crate::BufMut
+pub struct PooledBuf {
+    buf: Vec<u8>,
+    // taken by `freeze`, which hands the buffer over to the frozen `Bytes`
+    pool: Option<Arc<Shared>>,
+}
+
+impl PooledBuf {
+    /// Converts into `Bytes` without copying. The buffer goes back to the pool when the last
+    /// handle sharing it is dropped.
+    pub fn freeze(mut self) -> Bytes<'static> {
+        let buf = mem::take(&mut self.buf);
+        let pool = self.pool.take().expect("pooled buffer was already frozen");
+        Bytes::from_owner_with_drop(buf, move |buf| pool.recycle(buf))
+    }
+}
+
+impl Deref for PooledBuf {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.buf
+    }
+}
+
+impl DerefMut for PooledBuf {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.buf
+    }
+}
+
+impl fmt::Debug for PooledBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledBuf")
+            .field("len", &self.buf.len())
+            .field("capacity", &self.buf.capacity())
+            .finish()
+    }
+}
+
+impl Drop for PooledBuf {
+    fn drop(&mut self) {
+        if let Some(pool) = &self.pool {
+            pool.recycle(mem::take(&mut self.buf));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufMut;
+
+    #[test]
+    fn recycle() {
+        let pool = BytesPool::new(64, 1);
+
+        let mut buf = pool.get();
+        buf.put_slice(b"frame");
+        let frame = buf.freeze();
+        let header = frame.slice(..2);
+        drop(frame);
+        assert_eq!(pool.stats().in_use, 1);
+
+        drop(header);
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                idle: 1,
+                in_use: 0,
+                allocated: 1,
+                reused: 0,
+            }
+        );
+
+        let buf = pool.get();
+        assert!(buf.is_empty());
+        assert!(buf.capacity() >= 64);
+        let other = pool.get();
+        drop((buf, other));
+        let stats = pool.stats();
+        assert_eq!((stats.idle, stats.allocated, stats.reused), (1, 2, 1));
+
+        let mut grown = pool.get();
+        grown.extend_from_slice(&[0; 4096]);
+        drop(grown);
+        assert!(pool.get().capacity() < 4096);
+    }
+}
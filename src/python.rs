@@ -0,0 +1,177 @@
+use crate::Bytes;
+use core::ffi::{c_int, c_void};
+use pyo3::{
+    buffer::PyBuffer,
+    exceptions::PyBufferError,
+    ffi,
+    prelude::*,
+    pybacked::PyBackedBytes,
+    types::{PyBytes, PyMemoryView},
+};
+
+/// Python object exposing a [`Bytes`] through the buffer protocol, so that `memoryview(obj)`
+/// and friends read the data in place.
+#[pyclass(frozen, name = "Bytes", module = "lifetimed_bytes")]
+pub struct PyBytesView {
+    inner: Bytes<'static>,
+}
+
+impl PyBytesView {
+    pub fn into_inner(self) -> Bytes<'static> {
+        self.inner
+    }
+}
+
+impl From<Bytes<'static>> for PyBytesView {
+    fn from(inner: Bytes<'static>) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyBytesView {
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("view is null"));
+        }
+
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("Bytes is read-only"));
+        }
+
+        let data: &[u8] = &slf.get().inner;
+
+        // SAFETY: `view` is non-null and provided by the interpreter; the data is kept alive by
+        // the reference to `slf` stored in `obj`, and `PyBytesView` is immutable.
+        unsafe {
+            (*view).buf = data.as_ptr() as *mut c_void;
+            (*view).len = data.len() as ffi::Py_ssize_t;
+            (*view).readonly = 1;
+            (*view).itemsize = 1;
+            (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+                c"B".as_ptr() as *mut _
+            } else {
+                core::ptr::null_mut()
+            };
+            (*view).ndim = 1;
+            (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+                &mut (*view).len
+            } else {
+                core::ptr::null_mut()
+            };
+            (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+                &mut (*view).itemsize
+            } else {
+                core::ptr::null_mut()
+            };
+            (*view).suboffsets = core::ptr::null_mut();
+            (*view).internal = core::ptr::null_mut();
+            (*view).obj = slf.into_any().into_ptr();
+        }
+
+        Ok(())
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner)
+    }
+}
+
+impl Bytes<'static> {
+    /// Wraps the buffer into a Python `memoryview` without copying.
+    pub fn into_memoryview(self, py: Python<'_>) -> PyResult<Bound<'_, PyMemoryView>> {
+        PyMemoryView::from(Bound::new(py, PyBytesView::from(self))?.as_any())
+    }
+}
+
+/// Borrows `bytes` objects and buffers exported by [`PyBytesView`] without copying; any other
+/// buffer-protocol object (`bytearray`, `memoryview`, ...) is copied.
+impl<'a, 'py> FromPyObject<'a, 'py> for Bytes<'static> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(view) = obj.cast::<PyBytesView>() {
+            return Ok(view.get().inner.clone());
+        }
+
+        if let Ok(b) = obj.cast::<PyBytes>() {
+            return Ok(bytes::Bytes::from_owner(PyBackedBytes::from(b.to_owned())).into());
+        }
+
+        Ok(PyBuffer::<u8>::get(&obj)?.to_vec(obj.py())?.into())
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Bytes<'static> {
+    type Target = PyBytes;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        Ok(PyBytes::new(py, &self))
+    }
+}
+
+impl<'py, 'b> IntoPyObject<'py> for &Bytes<'b> {
+    type Target = PyBytes;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        Ok(PyBytes::new(py, self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::mem::MaybeUninit;
+    use pyo3::types::PyByteArray;
+
+    #[test]
+    fn buffer_protocol() {
+        Python::attach(|py| {
+            let data = Bytes::from(Vec::from(*b"payload"));
+            let ptr = data.as_ptr();
+            let obj = Bound::new(py, PyBytesView::from(data)).unwrap();
+
+            let view = PyMemoryView::from(obj.as_any()).unwrap();
+            let buf = PyBuffer::<u8>::get(view.as_any()).unwrap();
+            assert_eq!(buf.buf_ptr().cast_const().cast(), ptr);
+            assert_eq!(buf.len_bytes(), 7);
+            assert!(buf.readonly());
+
+            let mut raw = MaybeUninit::<ffi::Py_buffer>::uninit();
+            // SAFETY: `obj` is a live object and `raw` is only read if the call succeeds
+            let ret = unsafe {
+                ffi::PyObject_GetBuffer(obj.as_ptr(), raw.as_mut_ptr(), ffi::PyBUF_WRITABLE)
+            };
+            assert_eq!(ret, -1);
+            assert!(PyErr::take(py).unwrap().is_instance_of::<PyBufferError>(py));
+        });
+    }
+
+    #[test]
+    fn extract() {
+        Python::attach(|py| {
+            let bytes = PyBytes::new(py, b"payload");
+            let shared: Bytes<'static> = bytes.extract().unwrap();
+            assert_eq!(shared.as_ptr(), bytes.as_bytes().as_ptr());
+
+            let array = PyByteArray::new(py, b"payload");
+            let copied: Bytes<'static> = array.extract().unwrap();
+            assert_eq!(copied, "payload");
+            // SAFETY: nothing mutates the bytearray while the slice is alive
+            assert_ne!(copied.as_ptr(), unsafe { array.as_bytes() }.as_ptr());
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use crate::Bytes;
+use core::cmp::Ordering;
+use redb::{AccessGuard, Key, TypeName, Value};
+
+/// Stores the raw bytes, with the same encoding as `&[u8]`. Reads borrow from the page the
+/// value lives in.
+impl Value for Bytes<'static> {
+    type SelfType<'a>
+        = Bytes<'a>
+    where
+        Self: 'a;
+    type AsBytes<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
+
+    fn fixed_width() -> Option<usize> {
+        None
+    }
+
+    fn from_bytes<'a>(data: &'a [u8]) -> Bytes<'a>
+    where
+        Self: 'a,
+    {
+        data.into()
+    }
+
+    fn as_bytes<'a, 'b: 'a>(value: &'a Bytes<'b>) -> &'a [u8]
+    where
+        Self: 'b,
+    {
+        value
+    }
+
+    fn type_name() -> TypeName {
+        TypeName::new("lifetimed_bytes::Bytes")
+    }
+}
+
+impl Key for Bytes<'static> {
+    fn compare(data1: &[u8], data2: &[u8]) -> Ordering {
+        data1.cmp(data2)
+    }
+}
+
+/// Borrows the value for as long as the guard is held.
+impl<'a> From<&'a AccessGuard<'_, &'static [u8]>> for Bytes<'a> {
+    fn from(guard: &'a AccessGuard<'_, &'static [u8]>) -> Self {
+        guard.value().into()
+    }
+}
+
+/// Adopts a guard returned by `ReadOnlyTable::get` as the owner without copying, so the value
+/// can outlive the table and transaction handles.
+impl<'b> From<AccessGuard<'static, &'static [u8]>> for Bytes<'b> {
+    fn from(guard: AccessGuard<'static, &'static [u8]>) -> Self {
+        Bytes::from_owner(Guard(guard))
+    }
+}
+
+struct Guard(AccessGuard<'static, &'static [u8]>);
+
+impl AsRef<[u8]> for Guard {
+    fn as_ref(&self) -> &[u8] {
+        self.0.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+    use alloc::vec::Vec;
+    use redb::{
+        backends::InMemoryBackend, Database, ReadableDatabase, ReadableTable, TableDefinition,
+    };
+
+    const RAW: TableDefinition<&str, &[u8]> = TableDefinition::new("raw");
+    const BYTES: TableDefinition<Bytes, Bytes> = TableDefinition::new("bytes");
+
+    #[test]
+    fn redb() {
+        let db = Database::builder()
+            .create_with_backend(InMemoryBackend::new())
+            .unwrap();
+        let tx = db.begin_write().unwrap();
+        {
+            let mut raw = tx.open_table(RAW).unwrap();
+            raw.insert("k", &b"raw value"[..]).unwrap();
+            let mut table = tx.open_table(BYTES).unwrap();
+            table
+                .insert(Bytes::from("b"), Bytes::from("second"))
+                .unwrap();
+            table
+                .insert(Bytes::from("a"), Bytes::from("first"))
+                .unwrap();
+        }
+        tx.commit().unwrap();
+
+        let tx = db.begin_read().unwrap();
+        let raw = tx.open_table(RAW).unwrap();
+        let guard = raw.get("k").unwrap().unwrap();
+        let borrowed = Bytes::from(&guard);
+        assert_eq!(borrowed.storage_kind(), StorageKind::Borrowed);
+        assert_eq!(borrowed, "raw value");
+
+        let owned = Bytes::from(raw.get("k").unwrap().unwrap()).slice(4..);
+        drop((raw, tx));
+        assert_eq!(owned, "value");
+
+        let tx = db.begin_read().unwrap();
+        let table = tx.open_table(BYTES).unwrap();
+        let keys: Vec<_> = table
+            .iter()
+            .unwrap()
+            .map(|e| e.unwrap().0.value().to_vec())
+            .collect();
+        assert_eq!(keys, [b"a", b"b"]);
+        assert_eq!(
+            table.get(Bytes::from("b")).unwrap().unwrap().value(),
+            "second"
+        );
+    }
+}
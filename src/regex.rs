@@ -0,0 +1,186 @@
+//! Matching `regex::bytes::Regex` against `Bytes`, with matches and capture groups returned
+//! as views of the haystack.
+
+use crate::Bytes;
+use core::ops::Range;
+use regex::bytes::{CaptureLocations, Regex};
+
+/// A match, as a view of the haystack along with its position in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<'b> {
+    bytes: Bytes<'b>,
+    start: usize,
+}
+
+impl<'b> Match<'b> {
+    fn new(haystack: &Bytes<'b>, range: Range<usize>) -> Self {
+        Self {
+            start: range.start,
+            bytes: haystack.slice(range),
+        }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.start + self.bytes.len()
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    pub fn as_bytes(&self) -> &Bytes<'b> {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Bytes<'b> {
+        self.bytes
+    }
+}
+
+/// Capture groups of a match; group 0 is the whole match.
+#[derive(Clone, Debug)]
+pub struct Captures<'b> {
+    haystack: Bytes<'b>,
+    locs: CaptureLocations,
+    regex: Regex,
+}
+
+impl<'b> Captures<'b> {
+    /// The group at index `i`, or `None` if it didn't participate in the match.
+    pub fn get(&self, i: usize) -> Option<Match<'b>> {
+        let (start, end) = self.locs.get(i)?;
+        Some(Match::new(&self.haystack, start..end))
+    }
+
+    /// The group named `name`, or `None` if there is no such group or it didn't participate.
+    pub fn name(&self, name: &str) -> Option<Match<'b>> {
+        let i = self.regex.capture_names().position(|n| n == Some(name))?;
+        self.get(i)
+    }
+
+    /// Number of groups, including group 0.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.locs.len()
+    }
+
+    /// Every group in order, `None` for those that didn't participate.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Match<'b>>> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+}
+
+impl<'b> Bytes<'b> {
+    /// The leftmost match of `regex`.
+    pub fn find_regex(&self, regex: &Regex) -> Option<Match<'b>> {
+        let m = regex.find(self)?;
+        Some(Match::new(self, m.range()))
+    }
+
+    /// Successive non-overlapping matches of `regex`.
+    pub fn find_iter_regex<'r>(&self, regex: &'r Regex) -> FindIter<'r, 'b> {
+        FindIter {
+            regex,
+            haystack: self.clone(),
+            pos: 0,
+            last_match: None,
+        }
+    }
+
+    /// Capture groups of the leftmost match of `regex`.
+    ///
+    /// ```
+    /// # use lifetimed_bytes::Bytes;
+    /// # use regex::bytes::Regex;
+    /// let re = Regex::new(r"(?<key>\w+)=(?<value>\w+)").unwrap();
+    /// let b = Bytes::from("user=alice");
+    /// let caps = b.captures_regex(&re).unwrap();
+    /// assert_eq!(caps.name("value").unwrap().as_bytes(), "alice");
+    /// assert_eq!(caps.get(1).unwrap().range(), 0..4);
+    /// ```
+    pub fn captures_regex(&self, regex: &Regex) -> Option<Captures<'b>> {
+        let mut locs = regex.capture_locations();
+        regex.captures_read(&mut locs, self)?;
+        Some(Captures {
+            haystack: self.clone(),
+            locs,
+            regex: regex.clone(),
+        })
+    }
+}
+
+/// Returned by [`Bytes::find_iter_regex`].
+#[derive(Clone, Debug)]
+pub struct FindIter<'r, 'b> {
+    regex: &'r Regex,
+    haystack: Bytes<'b>,
+    pos: usize,
+    last_match: Option<usize>,
+}
+
+impl<'r, 'b> Iterator for FindIter<'r, 'b> {
+    type Item = Match<'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pos > self.haystack.len() {
+                return None;
+            }
+
+            let m = self.regex.find_at(&self.haystack, self.pos)?;
+            if m.is_empty() {
+                // step past empty matches, skipping one right after the previous match
+                self.pos = m.end() + 1;
+                if self.last_match == Some(m.end()) {
+                    continue;
+                }
+            } else {
+                self.pos = m.end();
+            }
+            self.last_match = Some(m.end());
+            return Some(Match::new(&self.haystack, m.range()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn matches() {
+        let data = Vec::from(&b"id=17 id=4 id=256"[..]);
+        let b = Bytes::from(&data);
+        let re = Regex::new(r"id=(\d+)").unwrap();
+
+        let m = b.find_regex(&re).unwrap();
+        assert_eq!(
+            (m.range(), m.as_bytes().clone()),
+            (0..5, Bytes::from("id=17"))
+        );
+        assert_eq!(m.as_bytes().storage_kind(), StorageKind::Borrowed);
+
+        let ends = b.find_iter_regex(&re).map(|m| m.end()).collect::<Vec<_>>();
+        assert_eq!(ends, [5, 10, 17]);
+
+        let caps = b.slice(6..).captures_regex(&re).unwrap();
+        assert_eq!(caps.len(), 2);
+        assert_eq!(caps.get(1).unwrap().into_bytes(), "4");
+        assert!(caps.name("missing").is_none());
+
+        let empty = Regex::new(r"\d*").unwrap();
+        let b = Bytes::from("a12b");
+        let found = b
+            .find_iter_regex(&empty)
+            .map(|m| m.range())
+            .collect::<Vec<_>>();
+        assert_eq!(found, [0..0, 1..3, 4..4]);
+        assert!(b.find_regex(&Regex::new("x").unwrap()).is_none());
+    }
+}
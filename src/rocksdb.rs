@@ -0,0 +1,120 @@
+//! Zero-copy access to RocksDB values.
+//!
+//! **Unverified:** this module hasn't yet been built or tested against `librocksdb-sys` as
+//! generated by bindgen, nor run through the UI tests with the `rocksdb` feature enabled. Treat
+//! it as experimental until that has been done.
+
+use crate::{Bytes, OwnedBytes};
+use core::ops::Deref;
+use rocksdb::{DBAccess, DBPinnableSlice, DBRawIteratorWithThreadMode};
+use stable_deref_trait::StableDeref;
+
+/// A value pinned in the block cache or memtable, as returned by
+/// [`DB::get_pinned`](rocksdb::DB::get_pinned), usable as an [`OwnedBytes`] owner.
+///
+/// ```no_run
+/// # use lifetimed_bytes::{rocksdb::PinnedSlice, OwnedBytes};
+/// # let db = rocksdb::DB::open_default("db").unwrap();
+/// let header: Option<OwnedBytes<PinnedSlice>> = db
+///     .get_pinned(b"key")
+///     .unwrap()
+///     .map(|v| OwnedBytes::from(v).map(|b| b.slice(..4)));
+/// ```
+pub struct PinnedSlice<'a>(DBPinnableSlice<'a>);
+
+impl<'a> PinnedSlice<'a> {
+    pub fn into_inner(self) -> DBPinnableSlice<'a> {
+        self.0
+    }
+}
+
+impl<'a> From<DBPinnableSlice<'a>> for PinnedSlice<'a> {
+    fn from(slice: DBPinnableSlice<'a>) -> Self {
+        Self(slice)
+    }
+}
+
+impl Deref for PinnedSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// SAFETY: the data is owned by RocksDB and stays pinned until the slice is dropped, so moving
+// the handle doesn't move it
+unsafe impl StableDeref for PinnedSlice<'_> {}
+
+/// Bundles the pin with a view of the whole value.
+impl<'a> From<DBPinnableSlice<'a>> for OwnedBytes<PinnedSlice<'a>> {
+    fn from(slice: DBPinnableSlice<'a>) -> Self {
+        OwnedBytes::new(PinnedSlice(slice))
+    }
+}
+
+/// Borrows the pinned value.
+impl<'a> From<&'a DBPinnableSlice<'_>> for Bytes<'a> {
+    fn from(slice: &'a DBPinnableSlice<'_>) -> Self {
+        Bytes::from(&**slice)
+    }
+}
+
+/// Zero-copy accessors for [`DBRawIteratorWithThreadMode`]. The views borrow the iterator, as
+/// RocksDB invalidates them once it moves.
+pub trait RawIteratorExt {
+    /// The current key, or `None` if the iterator isn't valid.
+    fn key_bytes(&self) -> Option<Bytes<'_>>;
+
+    /// The current value, or `None` if the iterator isn't valid.
+    fn value_bytes(&self) -> Option<Bytes<'_>>;
+}
+
+impl<D: DBAccess> RawIteratorExt for DBRawIteratorWithThreadMode<'_, D> {
+    fn key_bytes(&self) -> Option<Bytes<'_>> {
+        self.key().map(Bytes::from)
+    }
+
+    fn value_bytes(&self) -> Option<Bytes<'_>> {
+        self.value().map(Bytes::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+    use alloc::format;
+    use rocksdb::{Options, DB};
+
+    #[test]
+    fn pinned() {
+        let path =
+            std::env::temp_dir().join(format!("lifetimed-bytes-rocksdb-{}", std::process::id()));
+        {
+            let db = DB::open_default(&path).unwrap();
+            db.put(b"k1", b"value one").unwrap();
+            db.put(b"k2", b"value two").unwrap();
+
+            let owned = OwnedBytes::from(db.get_pinned(b"k1").unwrap().unwrap());
+            let owned = owned.map(|b| b.slice(6..));
+            assert_eq!(owned.bytes(), "one");
+
+            let pinned = db.get_pinned(b"k2").unwrap().unwrap();
+            let value = Bytes::from(&pinned);
+            assert_eq!(value.storage_kind(), StorageKind::Borrowed);
+            assert_eq!(value, "value two");
+
+            let mut iter = db.raw_iterator();
+            iter.seek_to_first();
+            assert_eq!(iter.key_bytes().unwrap(), "k1");
+            assert_eq!(iter.value_bytes().unwrap(), "value one");
+            iter.next();
+            assert_eq!(iter.key_bytes().unwrap(), "k2");
+            iter.next();
+            assert!(iter.key_bytes().is_none());
+            assert!(iter.value_bytes().is_none());
+        }
+        DB::destroy(&Options::default(), &path).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Bytes;
+use alloc::{borrow::Cow, vec::Vec};
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+/// Matches the default serde representation, a sequence of bytes.
+impl<'b> JsonSchema for Bytes<'b> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Bytes".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod text {
+    use crate::serde::{Base64, Hex};
+    use alloc::borrow::Cow;
+    use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+    impl<'b> JsonSchema for Hex<'b> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            "HexBytes".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "contentEncoding": "base16",
+                "pattern": "^([0-9a-fA-F]{2})*$",
+            })
+        }
+    }
+
+    impl<'b> JsonSchema for Base64<'b> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            "Base64Bytes".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "contentEncoding": "base64",
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{serde::Hex, Bytes};
+    use schemars::schema_for;
+
+    #[test]
+    fn schemas() {
+        assert_eq!(schema_for!(Bytes).get("type").unwrap(), "array");
+        assert_eq!(schema_for!(Hex).get("contentEncoding").unwrap(), "base16");
+    }
+}
@@ -0,0 +1,144 @@
+use crate::Bytes;
+use alloc::{collections::VecDeque, vec::Vec};
+use bytes::Buf;
+use core::iter::FromIterator;
+
+/// A sequence of [`Bytes`] segments read as a single buffer, without copying them together.
+#[derive(Clone, Debug, Default)]
+pub struct SegmentedBytes<'b> {
+    segments: VecDeque<Bytes<'b>>,
+    len: usize,
+}
+
+impl<'b> SegmentedBytes<'b> {
+    pub const fn new() -> Self {
+        Self {
+            segments: VecDeque::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a segment. Empty segments are dropped.
+    pub fn push(&mut self, segment: Bytes<'b>) {
+        if !segment.is_empty() {
+            self.len += segment.len();
+            self.segments.push_back(segment);
+        }
+    }
+
+    pub fn segments(&self) -> impl ExactSizeIterator<Item = &Bytes<'b>> + '_ {
+        self.segments.iter()
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Joins the segments into a single buffer. Copies only if there is more than one segment.
+    pub fn into_contiguous(mut self) -> Bytes<'b> {
+        match self.segments.len() {
+            0 => Bytes::new(),
+            1 => self.segments.pop_front().unwrap(),
+            _ => {
+                crate::instrument::record_copy(self.len);
+                let mut v = Vec::with_capacity(self.len);
+                for segment in &self.segments {
+                    v.extend_from_slice(segment);
+                }
+                v.into()
+            }
+        }
+    }
+}
+
+impl<'b> Buf for SegmentedBytes<'b> {
+    fn remaining(&self) -> usize {
+        self.len
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.segments.front().map_or(&[], |segment| segment)
+    }
+
+    fn advance(&mut self, mut cnt: usize) {
+        assert!(
+            cnt <= self.len,
+            "cannot advance past `remaining`: {:?} <= {:?}",
+            cnt,
+            self.len
+        );
+
+        self.len -= cnt;
+        while cnt > 0 {
+            let front = self.segments.front_mut().unwrap();
+            if cnt < front.len() {
+                front.advance(cnt);
+                break;
+            }
+            cnt -= front.len();
+            self.segments.pop_front();
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [std::io::IoSlice<'a>]) -> usize {
+        let mut n = 0;
+        for (slot, segment) in dst.iter_mut().zip(&self.segments) {
+            *slot = segment.as_io_slice();
+            n += 1;
+        }
+        n
+    }
+
+    fn copy_to_bytes(&mut self, len: usize) -> bytes::Bytes {
+        match self.segments.front_mut() {
+            Some(front) if front.len() >= len => {
+                let out = front.copy_to_bytes(len);
+                if front.is_empty() {
+                    self.segments.pop_front();
+                }
+                self.len -= len;
+                out
+            }
+            _ => {
+                assert!(len <= self.len, "`len` greater than remaining");
+                crate::instrument::record_copy(len);
+                let mut out = bytes::BytesMut::with_capacity(len);
+                bytes::BufMut::put(&mut out, self.take(len));
+                out.freeze()
+            }
+        }
+    }
+}
+
+impl<'b> From<Bytes<'b>> for SegmentedBytes<'b> {
+    fn from(segment: Bytes<'b>) -> Self {
+        let mut s = Self::new();
+        s.push(segment);
+        s
+    }
+}
+
+impl<'b> Extend<Bytes<'b>> for SegmentedBytes<'b> {
+    fn extend<T: IntoIterator<Item = Bytes<'b>>>(&mut self, iter: T) {
+        for segment in iter {
+            self.push(segment);
+        }
+    }
+}
+
+impl<'b> FromIterator<Bytes<'b>> for SegmentedBytes<'b> {
+    fn from_iter<T: IntoIterator<Item = Bytes<'b>>>(iter: T) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
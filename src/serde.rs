@@ -0,0 +1,406 @@
+//! Serde support.
+//!
+//! `Bytes` serializes as a plain byte string. For human-readable formats the [`hex`] and
+//! [`base64`] modules (for use with `#[serde(with = "...")]`) and the [`Hex`] and [`Base64`]
+//! wrappers encode it as text instead, while binary formats still get raw bytes.
+//!
+//! Deserializing a plain `Bytes` always copies. The [`borrow`](mod@borrow) module borrows from
+//! the input instead when the format allows it, which is what zero-copy formats like postcard
+//! are for.
+
+use crate::Bytes;
+use alloc::{string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<'b> Serialize for Bytes<'b> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+impl<'de, 'b> Deserialize<'de> for Bytes<'b> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        crate::instrument::record_copy(v.len());
+        Ok(v.to_vec().into())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.visit_byte_buf(v.into_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(b) = seq.next_element()? {
+            v.push(b);
+        }
+        crate::instrument::record_copy(v.len());
+        Ok(v.into())
+    }
+}
+
+struct BorrowedBytesVisitor<'b> {
+    max_len: usize,
+    _marker: PhantomData<&'b ()>,
+}
+
+impl<'b> BorrowedBytesVisitor<'b> {
+    fn check<E: de::Error>(&self, len: usize) -> Result<(), E> {
+        if len > self.max_len {
+            return Err(E::invalid_length(len, self));
+        }
+        Ok(())
+    }
+}
+
+impl<'de: 'b, 'b> Visitor<'de> for BorrowedBytesVisitor<'b> {
+    type Value = Bytes<'b>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.max_len == usize::MAX {
+            f.write_str("a byte string")
+        } else {
+            write!(f, "a byte string of at most {} bytes", self.max_len)
+        }
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        self.check(v.len())?;
+        Ok(Bytes::from(v))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_borrowed_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.check(v.len())?;
+        crate::instrument::record_copy(v.len());
+        Ok(v.to_vec().into())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.check(v.len())?;
+        crate::instrument::record_copy(v.len());
+        Ok(v.into())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.visit_byte_buf(v.into_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096).min(self.max_len));
+        while let Some(b) = seq.next_element()? {
+            self.check(v.len() + 1)?;
+            v.push(b);
+        }
+        crate::instrument::record_copy(v.len());
+        Ok(v.into())
+    }
+}
+
+trait TextEncoding {
+    const EXPECTING: &'static str;
+
+    fn encode(data: &[u8]) -> String;
+    fn decode(s: &str) -> Option<Vec<u8>>;
+}
+
+enum HexEncoding {}
+
+impl TextEncoding for HexEncoding {
+    const EXPECTING: &'static str = "a hex string";
+
+    fn encode(data: &[u8]) -> String {
+        crate::hex::encode(data)
+    }
+
+    fn decode(s: &str) -> Option<Vec<u8>> {
+        crate::hex::decode(s.as_bytes())
+    }
+}
+
+enum Base64Encoding {}
+
+impl TextEncoding for Base64Encoding {
+    const EXPECTING: &'static str = "a base64 string";
+
+    fn encode(data: &[u8]) -> String {
+        ::base64::Engine::encode(&::base64::engine::general_purpose::STANDARD, data)
+    }
+
+    fn decode(s: &str) -> Option<Vec<u8>> {
+        ::base64::Engine::decode(&::base64::engine::general_purpose::STANDARD, s).ok()
+    }
+}
+
+struct TextVisitor<E>(PhantomData<E>);
+
+impl<'de, E: TextEncoding> Visitor<'de> for TextVisitor<E> {
+    type Value = Bytes<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(E::EXPECTING)
+    }
+
+    fn visit_str<Er: de::Error>(self, v: &str) -> Result<Self::Value, Er> {
+        E::decode(v)
+            .map(Bytes::from)
+            .ok_or_else(|| Er::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+fn serialize_text<E: TextEncoding, S: Serializer>(
+    data: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&E::encode(data))
+    } else {
+        serializer.serialize_bytes(data)
+    }
+}
+
+fn deserialize_text<'de, 'b, E: TextEncoding, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Bytes<'b>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(TextVisitor::<E>(PhantomData))
+    } else {
+        Bytes::deserialize(deserializer)
+    }
+}
+
+/// Hex text in human-readable formats, raw bytes otherwise.
+///
+/// With the `schemars` feature, pair it with `#[schemars(with = "lifetimed_bytes::serde::Hex")]`,
+/// and likewise for [`base64`](mod@base64).
+///
+/// ```
+/// # use lifetimed_bytes::Bytes;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Block {
+///     #[serde(with = "lifetimed_bytes::serde::hex")]
+///     hash: Bytes<'static>,
+/// }
+/// ```
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_text::<HexEncoding, S>(data, serializer)
+    }
+
+    pub fn deserialize<'de, 'b, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Bytes<'b>, D::Error> {
+        deserialize_text::<HexEncoding, D>(deserializer)
+    }
+}
+
+/// Base64 (standard alphabet, padded) text in human-readable formats, raw bytes otherwise.
+pub mod base64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_text::<Base64Encoding, S>(data, serializer)
+    }
+
+    pub fn deserialize<'de, 'b, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Bytes<'b>, D::Error> {
+        deserialize_text::<Base64Encoding, D>(deserializer)
+    }
+}
+
+/// Zero-copy deserialization borrowing from the input, for use with
+/// `#[serde(with = "lifetimed_bytes::serde::borrow", borrow)]`.
+///
+/// Formats that can't hand out borrowed data fall back to copying. [`deserialize_max`] also
+/// rejects inputs longer than `N` bytes.
+///
+/// [`deserialize_max`]: borrow::deserialize_max
+///
+/// ```
+/// # use lifetimed_bytes::Bytes;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Frame<'a> {
+///     #[serde(with = "lifetimed_bytes::serde::borrow", borrow)]
+///     payload: Bytes<'a>,
+///     #[serde(
+///         deserialize_with = "lifetimed_bytes::serde::borrow::deserialize_max::<_, 16>",
+///         borrow
+///     )]
+///     tag: Bytes<'a>,
+/// }
+/// ```
+pub mod borrow {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(data)
+    }
+
+    pub fn deserialize<'de: 'b, 'b, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Bytes<'b>, D::Error> {
+        deserialize_max::<D, { usize::MAX }>(deserializer)
+    }
+
+    pub fn deserialize_max<'de: 'b, 'b, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Bytes<'b>, D::Error> {
+        deserializer.deserialize_bytes(BorrowedBytesVisitor {
+            max_len: N,
+            _marker: PhantomData,
+        })
+    }
+}
+
+macro_rules! text_wrapper {
+    ($name:ident, $module:ident) => {
+        #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name<'b>(pub Bytes<'b>);
+
+        impl<'b> From<Bytes<'b>> for $name<'b> {
+            fn from(b: Bytes<'b>) -> Self {
+                Self(b)
+            }
+        }
+
+        impl<'b> From<$name<'b>> for Bytes<'b> {
+            fn from(w: $name<'b>) -> Self {
+                w.0
+            }
+        }
+
+        impl<'b> core::ops::Deref for $name<'b> {
+            type Target = Bytes<'b>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<'b> Serialize for $name<'b> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, 'b> Deserialize<'de> for $name<'b> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $module::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+text_wrapper!(Hex, hex);
+text_wrapper!(Base64, base64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        raw: Bytes<'static>,
+        #[serde(with = "hex")]
+        hex: Bytes<'static>,
+        b64: Base64<'static>,
+    }
+
+    #[test]
+    fn human_readable() {
+        let r = Record {
+            raw: Bytes::from(b"\x01\x02"),
+            hex: Bytes::from(b"\xde\xad"),
+            b64: Base64(Bytes::from("hi!")),
+        };
+
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(json, r#"{"raw":[1,2],"hex":"dead","b64":"aGkh"}"#);
+
+        let r: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(r.raw, b"\x01\x02");
+        assert_eq!(r.hex, b"\xde\xad");
+        assert_eq!(*r.b64, "hi!");
+
+        assert!(serde_json::from_str::<Record>(r#"{"raw":[],"hex":"xy","b64":""}"#).is_err());
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Frame<'a> {
+        id: u32,
+        #[serde(with = "borrow", borrow)]
+        payload: Bytes<'a>,
+        #[serde(
+            serialize_with = "borrow::serialize",
+            deserialize_with = "borrow::deserialize_max::<_, 4>",
+            borrow
+        )]
+        tag: Bytes<'a>,
+    }
+
+    #[test]
+    fn postcard() {
+        let frame = Frame {
+            id: 7,
+            payload: Bytes::from(b"telemetry"),
+            tag: Bytes::from("abcd"),
+        };
+        let buf = postcard::to_allocvec(&frame).unwrap();
+
+        let owned: Bytes<'static> =
+            postcard::from_bytes(&postcard::to_allocvec(&frame.payload).unwrap()).unwrap();
+        assert_eq!(owned, "telemetry");
+        assert_eq!(owned.storage_kind(), crate::StorageKind::Unique);
+
+        let decoded: Frame<'_> = postcard::from_bytes(&buf).unwrap();
+        assert_eq!(decoded.id, 7);
+        assert_eq!(decoded.payload, "telemetry");
+        assert_eq!(decoded.payload.storage_kind(), crate::StorageKind::Borrowed);
+        assert!(buf.as_ptr_range().contains(&decoded.payload.as_ptr()));
+        assert_eq!(decoded.tag, "abcd");
+
+        let long = Frame {
+            tag: Bytes::from("abcde"),
+            ..frame
+        };
+        let buf = postcard::to_allocvec(&long).unwrap();
+        assert!(postcard::from_bytes::<Frame<'_>>(&buf).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+//! POSIX shared memory mappings exposed as borrowed `Bytes`.
+
+use crate::Bytes;
+use core::{ffi::CStr, ptr, slice};
+use std::io;
+
+/// A POSIX shared memory object (`shm_open`) mapped into this process.
+///
+/// [`SharedMemory::as_bytes`] borrows the mapping, so views can't outlive it. Nothing stops
+/// another process, or another handle in this one, from writing to the object while a view is
+/// alive, so mapping it is `unsafe`: callers must rule that out through their own
+/// synchronization (e.g. a ring buffer protocol), as with `memmap2::Mmap::map`.
+#[derive(Debug)]
+pub struct SharedMemory {
+    ptr: *mut u8,
+    len: usize,
+    writable: bool,
+}
+
+// The mapping is plain memory owned by this handle.
+unsafe impl Send for SharedMemory {}
+unsafe impl Sync for SharedMemory {}
+
+impl SharedMemory {
+    /// Creates a new object of `len` bytes and maps it read-write. Fails if `name` exists.
+    ///
+    /// # Safety
+    ///
+    /// No other handle or process may write to the object while a slice returned by
+    /// [`SharedMemory::as_bytes`] is alive, nor access it at all while one returned by
+    /// [`SharedMemory::as_mut_slice`] is.
+    pub unsafe fn create(name: &CStr, len: usize) -> io::Result<Self> {
+        // SAFETY: `name` is a valid NUL-terminated C string
+        let fd = cvt(unsafe {
+            libc::shm_open(
+                name.as_ptr(),
+                libc::O_CREAT | libc::O_EXCL | libc::O_RDWR,
+                0o600,
+            )
+        })?;
+        // SAFETY: `fd` is an open descriptor we own
+        let res = cvt(unsafe { libc::ftruncate(fd, len as libc::off_t) })
+            .and_then(|_| Self::map(fd, len, true));
+        // SAFETY: `fd` is an open descriptor we own, and the mapping doesn't need it
+        unsafe { libc::close(fd) };
+        if res.is_err() {
+            // SAFETY: `name` is a valid NUL-terminated C string
+            unsafe { libc::shm_unlink(name.as_ptr()) };
+        }
+        res
+    }
+
+    /// Maps an existing object read-only, covering its whole current size.
+    ///
+    /// # Safety
+    ///
+    /// No other handle or process may write to the object while a slice returned by
+    /// [`SharedMemory::as_bytes`] is alive, nor shrink it while the mapping exists.
+    pub unsafe fn open(name: &CStr) -> io::Result<Self> {
+        // SAFETY: `name` is a valid NUL-terminated C string
+        let fd = cvt(unsafe { libc::shm_open(name.as_ptr(), libc::O_RDONLY, 0) })?;
+        let res = Self::file_len(fd).and_then(|len| Self::map(fd, len, false));
+        // SAFETY: `fd` is an open descriptor we own, and the mapping doesn't need it
+        unsafe { libc::close(fd) };
+        res
+    }
+
+    /// Removes the name; existing mappings stay valid until dropped.
+    pub fn unlink(name: &CStr) -> io::Result<()> {
+        // SAFETY: `name` is a valid NUL-terminated C string
+        cvt(unsafe { libc::shm_unlink(name.as_ptr()) }).map(drop)
+    }
+
+    fn file_len(fd: libc::c_int) -> io::Result<usize> {
+        // SAFETY: `stat` is a plain C struct for which all-zero bytes are a valid value
+        let mut stat = unsafe { core::mem::zeroed::<libc::stat>() };
+        // SAFETY: `fd` is an open descriptor and `stat` is a valid place to write the result
+        cvt(unsafe { libc::fstat(fd, &mut stat) })?;
+        Ok(stat.st_size as usize)
+    }
+
+    fn map(fd: libc::c_int, len: usize, writable: bool) -> io::Result<Self> {
+        if len == 0 {
+            return Ok(Self {
+                ptr: ptr::NonNull::dangling().as_ptr(),
+                len,
+                writable,
+            });
+        }
+
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+        // SAFETY: `fd` is an open descriptor and `len` is non-zero; the kernel picks the address
+        let ptr = unsafe { libc::mmap(ptr::null_mut(), len, prot, libc::MAP_SHARED, fd, 0) };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            ptr: ptr.cast(),
+            len,
+            writable,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Borrows the mapping without copying.
+    pub fn as_bytes(&self) -> Bytes<'_> {
+        // SAFETY: the caller of `create`/`open` guarantees no concurrent writes
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }.into()
+    }
+
+    /// Mutable access to the mapping, or `None` if it was opened read-only.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [u8]> {
+        // SAFETY: the caller of `create` guarantees exclusive access while this is alive
+        self.writable
+            .then(|| unsafe { slice::from_raw_parts_mut(self.ptr, self.len) })
+    }
+}
+
+impl Drop for SharedMemory {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // SAFETY: `ptr`/`len` come from the successful `mmap` in `map`, and nothing borrows
+            // the mapping any more
+            unsafe { libc::munmap(self.ptr.cast(), self.len) };
+        }
+    }
+}
+
+fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+    use alloc::format;
+    use std::ffi::CString;
+
+    #[test]
+    fn create_and_open() {
+        let name = CString::new(format!("/lifetimed-bytes-{}", std::process::id())).unwrap();
+
+        // SAFETY: the writer is done with its slice before the reader maps the object
+        let mut writer = unsafe { SharedMemory::create(&name, 16) }.unwrap();
+        writer.as_mut_slice().unwrap()[..5].copy_from_slice(b"hello");
+
+        let mut reader = unsafe { SharedMemory::open(&name) }.unwrap();
+        SharedMemory::unlink(&name).unwrap();
+        assert_eq!(reader.len(), 16);
+        assert!(reader.as_mut_slice().is_none());
+
+        let b = reader.as_bytes();
+        assert_eq!(b.storage_kind(), StorageKind::Borrowed);
+        assert_eq!(b.slice(..5), "hello");
+        // SAFETY: the name is unlinked, so this can't map anything
+        assert!(unsafe { SharedMemory::open(&name) }.is_err());
+    }
+}
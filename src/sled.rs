@@ -0,0 +1,35 @@
+use crate::Bytes;
+use sled::IVec;
+
+/// Shares the value without copying.
+impl<'b> From<IVec> for Bytes<'b> {
+    fn from(value: IVec) -> Self {
+        Bytes::from_owner(value)
+    }
+}
+
+/// Copies the data, as `IVec` can't adopt foreign storage.
+impl<'b> From<Bytes<'b>> for IVec {
+    fn from(bytes: Bytes<'b>) -> Self {
+        IVec::from(&*bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+
+    #[test]
+    fn ivec() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        db.insert("key", &[7; 64][..]).unwrap();
+
+        let value = Bytes::from(db.get("key").unwrap().unwrap());
+        assert_eq!(value.storage_kind(), StorageKind::Shared);
+        assert_eq!(value, [7; 64][..]);
+
+        db.insert("tail", IVec::from(value.slice(60..))).unwrap();
+        assert_eq!(db.get("tail").unwrap().unwrap(), [7; 4]);
+    }
+}
@@ -0,0 +1,118 @@
+//! SSZ support.
+//!
+//! `Bytes` encodes as a variable-length byte list with no length limit. Use [`ByteList`] for
+//! `List[uint8, MAX]` fields, which rejects longer inputs when decoding.
+
+use crate::Bytes;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use ssz::{Decode, DecodeError, Encode};
+
+impl<'b> Encode for Bytes<'b> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'b> Decode for Bytes<'b> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Ok(bytes.to_vec().into())
+    }
+}
+
+/// SSZ `List[uint8, MAX]`: `Bytes` holding at most `MAX` bytes.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByteList<'b, const MAX: usize>(Bytes<'b>);
+
+impl<'b, const MAX: usize> ByteList<'b, MAX> {
+    /// Returns `None` if `bytes` is longer than `MAX`.
+    pub fn new(bytes: Bytes<'b>) -> Option<Self> {
+        (bytes.len() <= MAX).then_some(Self(bytes))
+    }
+
+    pub fn into_inner(self) -> Bytes<'b> {
+        self.0
+    }
+}
+
+impl<'b, const MAX: usize> Deref for ByteList<'b, MAX> {
+    type Target = Bytes<'b>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'b, const MAX: usize> From<ByteList<'b, MAX>> for Bytes<'b> {
+    fn from(list: ByteList<'b, MAX>) -> Self {
+        list.0
+    }
+}
+
+impl<'b, const MAX: usize> Encode for ByteList<'b, MAX> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.0.ssz_append(buf)
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.0.ssz_bytes_len()
+    }
+}
+
+impl<'b, const MAX: usize> Decode for ByteList<'b, MAX> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() > MAX {
+            return Err(DecodeError::InvalidByteLength {
+                len: bytes.len(),
+                expected: MAX,
+            });
+        }
+        Bytes::from_ssz_bytes(bytes).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let b = Bytes::from("graffiti");
+        let encoded = b.as_ssz_bytes();
+        assert_eq!(encoded, b"graffiti");
+        assert_eq!(Bytes::from_ssz_bytes(&encoded).unwrap(), b);
+
+        let list = ByteList::<8>::new(b.clone()).unwrap();
+        assert_eq!(list.as_ssz_bytes(), encoded);
+        assert_eq!(ByteList::<8>::from_ssz_bytes(&encoded).unwrap(), list);
+
+        assert!(ByteList::<4>::new(b).is_none());
+        assert!(ByteList::<4>::from_ssz_bytes(&encoded).is_err());
+
+        // Inside a container the variable-length field goes behind an offset.
+        let encoded = (7u64, list.clone()).as_ssz_bytes();
+        assert_eq!(encoded.len(), 8 + 4 + 8);
+        let (n, decoded) = <(u64, ByteList<8>)>::from_ssz_bytes(&encoded).unwrap();
+        assert_eq!((n, decoded), (7, list));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Bytes, SegmentedBytes};
+use bytes::BytesMut;
+use core::{
+    future::poll_fn,
+    pin::{pin, Pin},
+    task::{ready, Context, Poll},
+};
+use futures_core::Stream;
+use futures_io::AsyncRead;
+use std::io;
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Reads an [`AsyncRead`] as a stream of owned chunks of up to `chunk_size` bytes.
+#[derive(Debug)]
+pub struct ReaderStream<R> {
+    reader: Option<R>,
+    buf: BytesMut,
+    chunk_size: usize,
+}
+
+impl<R> ReaderStream<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        Self {
+            reader: Some(reader),
+            buf: BytesMut::new(),
+            chunk_size,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for ReaderStream<R> {
+    type Item = io::Result<Bytes<'static>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some(reader) = this.reader.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        this.buf.resize(this.chunk_size, 0);
+        loop {
+            match ready!(Pin::new(&mut *reader).poll_read(cx, &mut this.buf)) {
+                Ok(0) => {
+                    this.reader = None;
+                    return Poll::Ready(None);
+                }
+                Ok(n) => {
+                    return Poll::Ready(Some(Ok(this.buf.split_to(n).into())));
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    this.reader = None;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Drains a stream of chunks into a [`SegmentedBytes`] without copying them, stopping at the
+/// first error.
+pub async fn collect_segments<'b, S, E>(stream: S) -> Result<SegmentedBytes<'b>, E>
+where
+    S: Stream<Item = Result<Bytes<'b>, E>>,
+{
+    let mut stream = pin!(stream);
+    let mut out = SegmentedBytes::new();
+    while let Some(chunk) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+        out.push(chunk?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, io::Cursor};
+
+    #[test]
+    fn roundtrip() {
+        let data = b"0123456789".repeat(10);
+        let stream = ReaderStream::with_chunk_size(Cursor::new(data.clone()), 32);
+        let segments = block_on(collect_segments(stream)).unwrap();
+        assert_eq!(segments.segment_count(), 4);
+        assert_eq!(segments.into_contiguous(), data);
+    }
+}
@@ -0,0 +1,98 @@
+//! tokio-uring integration.
+//!
+//! Reads into a registered [`FixedBuf`] can be exposed as [`LocalBytes`] without copying; the
+//! buffer goes back to its registry once the last handle is dropped. `FixedBuf` is tied to its
+//! runtime thread, so it can't back the `Send` [`Bytes`] type. `Bytes<'static>` and
+//! `LocalBytes<'static>` can in turn be handed to tokio-uring write operations directly.
+
+use crate::{Bytes, LocalBytes};
+use tokio_uring::buf::{fixed::FixedBuf, IoBuf};
+
+struct Registered(FixedBuf);
+
+impl AsRef<[u8]> for Registered {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<FixedBuf> for LocalBytes<'static> {
+    fn from(buf: FixedBuf) -> Self {
+        Self::from_owner(Registered(buf))
+    }
+}
+
+// Both point at heap or static memory that doesn't move with the handle.
+unsafe impl IoBuf for Bytes<'static> {
+    fn stable_ptr(&self) -> *const u8 {
+        self.as_ptr()
+    }
+
+    fn bytes_init(&self) -> usize {
+        self.len()
+    }
+
+    fn bytes_total(&self) -> usize {
+        self.len()
+    }
+}
+
+unsafe impl IoBuf for LocalBytes<'static> {
+    fn stable_ptr(&self) -> *const u8 {
+        self.as_ptr()
+    }
+
+    fn bytes_init(&self) -> usize {
+        self.len()
+    }
+
+    fn bytes_total(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+    use tokio_uring::{buf::fixed::FixedBufRegistry, fs::OpenOptions};
+
+    #[test]
+    fn read_fixed() {
+        let path =
+            std::env::temp_dir().join(format!("lifetimed-bytes-uring-{}", std::process::id()));
+
+        tokio_uring::start(async {
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .open(&path)
+                .await
+                .unwrap();
+            let (res, _) = file
+                .write_all_at(Bytes::from_static(b"fixed buffer"), 0)
+                .await;
+            res.unwrap();
+
+            let registry = FixedBufRegistry::new([vec![0; 64]]);
+            registry.register().unwrap();
+
+            let buf = registry.check_out(0).unwrap();
+            let (res, buf) = file.read_fixed_at(buf, 0).await;
+            assert_eq!(res.unwrap(), 12);
+
+            let b = LocalBytes::from(buf);
+            let word = b.slice(..5);
+            drop(b);
+            assert_eq!(word, "fixed");
+            assert!(registry.check_out(0).is_none());
+
+            drop(word);
+            assert!(registry.check_out(0).is_some());
+            file.close().await.unwrap();
+        });
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use crate::Bytes;
+use core::array::TryFromSliceError;
+use uuid::Uuid;
+
+impl<'b> Bytes<'b> {
+    /// Views the data as a [`Uuid`] if it is exactly 16 bytes long.
+    pub fn as_uuid(&self) -> Option<&Uuid> {
+        self.as_fixed().ok().map(Uuid::from_bytes_ref)
+    }
+}
+
+/// Borrows the 16 raw bytes.
+impl<'b> From<&'b Uuid> for Bytes<'b> {
+    fn from(uuid: &'b Uuid) -> Self {
+        uuid.as_bytes().into()
+    }
+}
+
+/// Copies the 16 raw bytes into owned storage.
+impl<'b> From<Uuid> for Bytes<'b> {
+    fn from(uuid: Uuid) -> Self {
+        uuid.as_bytes().to_vec().into()
+    }
+}
+
+impl<'a, 'b> TryFrom<&'a Bytes<'b>> for Uuid {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &'a Bytes<'b>) -> Result<Self, Self::Error> {
+        bytes.as_fixed().map(|a| Uuid::from_bytes(*a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+
+    #[test]
+    fn uuid() {
+        let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+        let borrowed = Bytes::from(&id);
+        assert_eq!(borrowed.storage_kind(), StorageKind::Borrowed);
+        assert_eq!(borrowed.as_uuid(), Some(&id));
+
+        let owned = Bytes::from(id);
+        assert_eq!(Uuid::try_from(&owned).unwrap(), id);
+        assert!(Uuid::try_from(&owned.slice(1..)).is_err());
+        assert!(owned.slice(..8).as_uuid().is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::Bytes;
+use js_sys::Uint8Array;
+
+impl<'b> Bytes<'b> {
+    /// Copies the buffer into a new JS-owned `Uint8Array`.
+    pub fn to_uint8array(&self) -> Uint8Array {
+        Uint8Array::from(&**self)
+    }
+
+    /// Creates a `Uint8Array` viewing this buffer directly in WASM linear memory, without copying.
+    ///
+    /// # Safety
+    ///
+    /// The view must not be used after `self` is dropped, and is invalidated by any growth of
+    /// linear memory, which may happen on any allocation. Do not allocate while holding it.
+    pub unsafe fn as_uint8array(&self) -> Uint8Array {
+        Uint8Array::view(self)
+    }
+}
+
+impl<'b> From<Uint8Array> for Bytes<'b> {
+    fn from(array: Uint8Array) -> Self {
+        Self::from(&array)
+    }
+}
+
+impl<'b> From<&Uint8Array> for Bytes<'b> {
+    fn from(array: &Uint8Array) -> Self {
+        array.to_vec().into()
+    }
+}
+
+impl<'b> From<&Bytes<'b>> for Uint8Array {
+    fn from(b: &Bytes<'b>) -> Self {
+        b.to_uint8array()
+    }
+}
+
+impl<'b> From<Bytes<'b>> for Uint8Array {
+    fn from(b: Bytes<'b>) -> Self {
+        b.to_uint8array()
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn uint8array() {
+        let b = Bytes::from("payload");
+        let array = Uint8Array::from(&b);
+        assert_eq!(array.to_vec(), b"payload");
+        assert_eq!(Bytes::from(array), "payload");
+
+        // SAFETY: nothing allocates while the view is alive
+        let view = unsafe { b.as_uint8array() };
+        assert_eq!(view.length(), 7);
+        assert_eq!(view.get_index(0), b'p');
+    }
+}
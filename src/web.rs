@@ -0,0 +1,38 @@
+use crate::Bytes;
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+
+/// Buffers the request body, reusing hyper's buffer when the body arrives in a single chunk.
+impl<S: Send + Sync> FromRequest<S> for Bytes<'static> {
+    type Rejection = BytesRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        bytes::Bytes::from_request(req, state).await.map(Self::from)
+    }
+}
+
+impl IntoResponse for Bytes<'static> {
+    fn into_response(self) -> Response {
+        bytes::Bytes::from(self).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum_core::body::Body;
+    use futures::executor::block_on;
+
+    #[test]
+    fn round_trip() {
+        let req = Request::new(Body::from("payload"));
+        let body: Bytes<'static> = block_on(Bytes::from_request(req, &())).unwrap();
+        assert_eq!(body, "payload");
+
+        let req = Request::new(body.slice(3..).into_response().into_body());
+        let echoed: Bytes<'static> = block_on(Bytes::from_request(req, &())).unwrap();
+        assert_eq!(echoed, "load");
+    }
+}
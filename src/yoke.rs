@@ -0,0 +1,52 @@
+use crate::Bytes;
+use core::mem;
+use yoke::Yokeable;
+
+// SAFETY: `Bytes<'a>` is covariant in `'a`
+unsafe impl<'a> Yokeable<'a> for Bytes<'static> {
+    type Output = Bytes<'a>;
+
+    fn transform(&'a self) -> &'a Bytes<'a> {
+        self
+    }
+
+    fn transform_owned(self) -> Bytes<'a> {
+        self
+    }
+
+    unsafe fn make(from: Bytes<'a>) -> Self {
+        // SAFETY: `Bytes<'a>` and `Bytes<'static>` have the same layout, and `Yokeable`'s
+        // contract only ever exposes the result at `'a`
+        mem::transmute::<Bytes<'a>, Bytes<'static>>(from)
+    }
+
+    fn transform_mut<F>(&'a mut self, f: F)
+    where
+        F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+    {
+        // SAFETY: `f` can't store anything borrowed for less than `'a`, see `Yokeable`
+        unsafe { f(mem::transmute::<&'a mut Self, &'a mut Bytes<'a>>(self)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageKind;
+    use alloc::boxed::Box;
+    use yoke::Yoke;
+
+    #[test]
+    fn yoke() {
+        let cart = Box::<[u8]>::from(&b"header;body"[..]);
+        let ptr = cart.as_ptr();
+
+        let yoke: Yoke<Bytes<'static>, Box<[u8]>> =
+            Yoke::attach_to_cart(cart, |data| Bytes::from(data));
+        let body: Yoke<Bytes<'static>, _> = yoke.map_project(|b, _| b.slice(7..));
+
+        assert_eq!(*body.get(), "body");
+        assert_eq!(body.get().storage_kind(), StorageKind::Borrowed);
+        assert_eq!(body.backing_cart().as_ptr(), ptr);
+    }
+}
@@ -0,0 +1,7 @@
+use lifetimed_bytes::{bytes, Bytes};
+
+const MAGIC: Bytes<'static> = bytes!(hex "cafebabz");
+
+fn main() {
+    println!("{:?}", MAGIC);
+}